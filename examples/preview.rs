@@ -0,0 +1,44 @@
+//! Renders the example bar from `main.rs` into a PNG without opening a window.
+//!
+//! Usage: `cargo run --example preview -- [output.png]`
+
+use wunderbar::ui::{
+    block::{Block, Props},
+    canvas::raster::RasterCanvas,
+    color::Color,
+    geometry::{Bounds, Padding},
+};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let output = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "preview.png".to_string());
+
+    let inner_block = Block::new(
+        (),
+        Props {
+            background_color: Some(Color::BLACK.into()),
+            min_width: Some(86),
+            min_height: Some(26),
+            ..Default::default()
+        },
+    );
+
+    let block = Block::new(
+        inner_block,
+        Props {
+            background_color: Some(Color::BLUE.into()),
+            padding: Some(Padding::uni(2)),
+            min_width: Some(90),
+            min_height: Some(30),
+            ..Default::default()
+        },
+    );
+
+    let canvas = RasterCanvas::render(&block, Bounds::new(0, 0, 1728, 40))?;
+    canvas.save_png(&output)?;
+
+    println!("wrote {output}");
+
+    Ok(())
+}
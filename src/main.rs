@@ -1,3 +1,4 @@
+#[cfg(target_os = "macos")]
 use wunderbar::ui::{
     app::App,
     block::{Block, Props},
@@ -7,6 +8,7 @@ use wunderbar::ui::{
     Drawable,
 };
 
+#[cfg(target_os = "macos")]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let app = App::new();
 
@@ -26,7 +28,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let inner_block = Block::new(
         (),
         Props {
-            background_color: Some(Color::BLACK),
+            background_color: Some(Color::BLACK.into()),
             min_width: Some(86),
             min_height: Some(26),
             ..Default::default()
@@ -36,7 +38,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let block = Block::new(
         inner_block,
         Props {
-            background_color: Some(Color::BLUE),
+            background_color: Some(Color::BLUE.into()),
             padding: Some(Padding::uni(2)),
             min_width: Some(90),
             min_height: Some(30),
@@ -53,3 +55,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[cfg(not(target_os = "macos"))]
+fn main() {
+    eprintln!("wunderbar can only display a bar on macOS");
+    std::process::exit(1);
+}
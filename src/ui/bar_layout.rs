@@ -0,0 +1,291 @@
+use super::{
+    canvas::Canvas,
+    geometry::{Bounds, Constraints, Point, Size},
+    Drawable, UiResult,
+};
+
+/// The classic status bar arrangement of a left, a center and a right zone, such as workspaces,
+/// the focused window's title and status items.
+///
+/// The center zone is sized by its content and always centered within the bounds, no matter how
+/// wide the side zones are. When the layout does not span the whole bar, such as inside a block
+/// with uneven padding, [`Props::center_within`] keeps the center zone centered on the bar or the
+/// screen instead. The left and right zones are placed against their edges and may only
+/// use the space up to the center zone, so content that does not fit has to shrink or truncate
+/// (for example with [`Overflow::Ellipsis`]) rather than run into the center.
+///
+/// Every zone spans the whole height of the bar, so zones usually hold a [`Layout`] that aligns
+/// their items vertically.
+///
+/// [`Overflow::Ellipsis`]: super::text::Overflow::Ellipsis
+/// [`Layout`]: super::layout::Layout
+pub struct BarLayout<Left = (), Center = (), Right = ()> {
+    left: Left,
+    center: Center,
+    right: Right,
+    props: Props,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Props {
+    /// Smallest space kept between the center zone and each of the side zones.
+    pub gap: usize,
+    /// Area to center the center zone within, such as the bounds of the whole bar or screen, or
+    /// [`None`] for the bounds of the layout. The center zone is moved towards the middle of
+    /// the layout's bounds only as far as needed to stay within them.
+    pub center_within: Option<Bounds>,
+}
+
+impl<Left: Drawable, Center: Drawable, Right: Drawable> BarLayout<Left, Center, Right> {
+    pub fn new(left: Left, center: Center, right: Right, props: Props) -> Self {
+        Self {
+            left,
+            center,
+            right,
+            props,
+        }
+    }
+
+    pub fn left(&self) -> &Left {
+        &self.left
+    }
+
+    pub fn left_mut(&mut self) -> &mut Left {
+        &mut self.left
+    }
+
+    pub fn center(&self) -> &Center {
+        &self.center
+    }
+
+    pub fn center_mut(&mut self) -> &mut Center {
+        &mut self.center
+    }
+
+    pub fn right(&self) -> &Right {
+        &self.right
+    }
+
+    pub fn right_mut(&mut self) -> &mut Right {
+        &mut self.right
+    }
+
+    pub fn props(&self) -> &Props {
+        &self.props
+    }
+
+    /// Returns the bounds of the left, center and right zones within `bounds`, on a canvas with
+    /// `scale` pixels per point.
+    fn zones(&self, bounds: Bounds, scale: f64) -> [Bounds; 3] {
+        let (x, y) = (bounds.position.x, bounds.position.y);
+        let Size { width, height } = bounds.size;
+        let gap = self.props.gap;
+
+        let measure = |child: &dyn Drawable, x, width| {
+            child
+                .measure(
+                    Point::new(x, y),
+                    Constraints::loose(Size::new(width, height)).with_scale(scale),
+                )
+                .width
+        };
+
+        let center_width = measure(&self.center, x, width);
+        let frame = self.props.center_within.unwrap_or(bounds);
+        let center_start = frame
+            .position
+            .x
+            .saturating_add(frame.size.width.saturating_sub(center_width) / 2)
+            .saturating_sub(x)
+            .min(width - center_width);
+        let center_end = center_start + center_width;
+
+        let left_space = center_start.saturating_sub(gap);
+        let left_width = measure(&self.left, x, left_space);
+
+        let right_space = width.saturating_sub(center_end.saturating_add(gap));
+        let right_start = width - right_space;
+        let right_width = measure(&self.right, x + right_start, right_space);
+
+        [
+            Bounds::new(x, y, left_width, height),
+            Bounds::new(x + center_start, y, center_width, height),
+            Bounds::new(x + width - right_width, y, right_width, height),
+        ]
+    }
+}
+
+impl<Left: Drawable, Center: Drawable, Right: Drawable> Drawable
+    for BarLayout<Left, Center, Right>
+{
+    /// The whole of the bounds.
+    fn content_size(&self, bounds: Bounds) -> Size {
+        bounds.size
+    }
+
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> UiResult<()> {
+        let [left, center, right] = self.zones(bounds, canvas.scale());
+
+        self.left.draw(canvas, left)?;
+        self.center.draw(canvas, center)?;
+        self.right.draw(canvas, right)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        block::{self, Block},
+        color::Color,
+        geometry::Padding,
+        layout::{self, Align, Flex, Layout},
+        snapshot::{assert_snapshot, reference_path},
+        text::{self, EllipsisPosition, Overflow, Text},
+    };
+
+    use super::*;
+
+    fn swatch(width: usize) -> Block {
+        Block::new(
+            (),
+            block::Props {
+                background_color: Some(Color::WHITE.into()),
+                width: Some(width),
+                height: Some(4),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn center_ignores_side_widths() {
+        let bar = BarLayout::new(
+            swatch(10),
+            swatch(20),
+            swatch(30),
+            Props {
+                gap: 2,
+                ..Default::default()
+            },
+        );
+        let bounds = Bounds::new(5, 0, 100, 10);
+
+        assert_eq!(bar.content_size(bounds), Size::new(100, 10));
+        assert_eq!(
+            bar.zones(bounds, 1.0),
+            [
+                Bounds::new(5, 0, 10, 10),
+                Bounds::new(45, 0, 20, 10),
+                Bounds::new(75, 0, 30, 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn sides_stop_short_of_center() {
+        let bar = BarLayout::new(
+            swatch(80),
+            swatch(20),
+            swatch(80),
+            Props {
+                gap: 4,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            bar.zones(Bounds::new(0, 0, 100, 10), 1.0),
+            [
+                Bounds::new(0, 0, 36, 10),
+                Bounds::new(40, 0, 20, 10),
+                Bounds::new(64, 0, 36, 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn center_within_frame() {
+        let props = Props {
+            center_within: Some(Bounds::new(0, 0, 100, 10)),
+            ..Default::default()
+        };
+        let bar = BarLayout::new(swatch(10), swatch(20), swatch(10), props);
+
+        // Inset by padding of 4 on the left and 8 on the right, the center stays at 40 to 60.
+        assert_eq!(
+            bar.zones(Bounds::new(4, 0, 88, 10), 1.0)[1],
+            Bounds::new(40, 0, 20, 10)
+        );
+        // Where the frame's center is out of reach, the center zone stays within the bounds.
+        assert_eq!(
+            bar.zones(Bounds::new(50, 0, 30, 10), 1.0)[1],
+            Bounds::new(50, 0, 20, 10)
+        );
+        assert_eq!(
+            bar.zones(Bounds::new(0, 0, 30, 10), 1.0)[1],
+            Bounds::new(10, 0, 20, 10)
+        );
+    }
+
+    #[test]
+    fn status_bar_zones() {
+        let label = |content: &str| {
+            Text::new(
+                content,
+                text::Props {
+                    fill: Color::WHITE.into(),
+                    overflow: Overflow::Ellipsis(EllipsisPosition::End),
+                    ..Default::default()
+                },
+            )
+        };
+        // Only the window title gives up space, so the workspace numbers stay readable.
+        let zone = |labels: &[&str]| {
+            let shrink = |content: &&str| if content.len() > 1 { 1.0 } else { 0.0 };
+            Layout::with_flex_children(
+                labels
+                    .iter()
+                    .map(|content| {
+                        let flex = Flex {
+                            shrink: shrink(content),
+                            ..Default::default()
+                        };
+                        (label(content), flex)
+                    })
+                    .collect(),
+                layout::Props {
+                    align_items: Align::Center,
+                    gap: 8,
+                    ..Default::default()
+                },
+            )
+        };
+        let zones = BarLayout::new(
+            zone(&["1", "2", "3", "Terminal — ~/src/wunderbar"]),
+            zone(&["Editor"]),
+            zone(&["58%", "12:45"]),
+            Props {
+                gap: 12,
+                ..Default::default()
+            },
+        );
+        let bar = Block::new(
+            zones,
+            block::Props {
+                background_color: Some(Color::rgb(0.12, 0.12, 0.14).into()),
+                width: Some(280),
+                height: Some(24),
+                padding: Some(Padding::new(8, 8, 0, 0)),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &bar,
+            Bounds::new(0, 0, 280, 24),
+            reference_path("bar_layout"),
+        );
+    }
+}
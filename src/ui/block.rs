@@ -1,12 +1,19 @@
+use std::cell::Cell;
+
 use super::{
+    canvas::{Canvas, Dash, FillRule, Path, StrokeStyle},
     color::Color,
-    geometry::{Bounds, Padding, Size},
+    geometry::{Bounds, Constraints, CornerRadius, Padding, Point, RectF, Size},
+    paint::Paint,
     Drawable, UiResult,
 };
 
 pub struct Block<Child = ()> {
     child: Child,
     props: Props,
+    /// The size the block was last measured at, which it fills when a parent draws it at that
+    /// size.
+    measured: Cell<Option<Size>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -17,100 +24,289 @@ pub struct Props {
     pub max_height: Option<usize>,
     pub width: Option<usize>,
     pub height: Option<usize>,
-    pub background_color: Option<Color>,
-    pub corner_radius: Option<usize>,
+    pub background_color: Option<Paint>,
+    pub corner_radius: Option<CornerRadius>,
     pub padding: Option<Padding>,
+    pub border: Option<Border>,
+    /// Shadow cast behind the block. It is not drawn underneath the block itself, so it does not
+    /// show through a translucent background.
+    pub shadow: Option<Shadow>,
+    /// Glow cast inwards from the edges of the block, on top of its background.
+    pub inner_glow: Option<Shadow>,
+    /// Whether the child is clipped to the block's padded bounds and rounded corners, so it cannot
+    /// draw over the block's neighbours or outside of its shape.
+    pub clip_children: bool,
+}
+
+/// A blurred copy of a [`Block`]'s shape, used for drop shadows and inner glows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shadow {
+    pub offset_x: isize,
+    pub offset_y: isize,
+    /// How far the edge of the shadow is blurred, with the same meaning as in CSS.
+    pub blur_radius: usize,
+    /// How far the shadow is grown (or shrunk, if negative) beyond the block's shape before
+    /// blurring. For an inner glow, this grows the glow inwards.
+    pub spread: isize,
+    pub color: Color,
+}
+
+impl Shadow {
+    /// Creates a shadow of `color`, blurred by `blur_radius`, directly underneath the block.
+    pub fn new(blur_radius: usize, color: Color) -> Self {
+        Self {
+            offset_x: 0,
+            offset_y: 0,
+            blur_radius,
+            spread: 0,
+            color,
+        }
+    }
+}
+
+/// A line drawn around the total bounds of a [`Block`], following its `corner_radius`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Border {
+    pub width: usize,
+    pub color: Color,
+    pub style: BorderStyle,
+    pub placement: BorderPlacement,
+}
+
+impl Border {
+    /// Creates a solid border drawn inside the block's bounds.
+    pub fn new(width: usize, color: Color) -> Self {
+        Self {
+            width,
+            color,
+            style: BorderStyle::default(),
+            placement: BorderPlacement::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BorderStyle {
+    #[default]
+    Solid,
+    /// Alternating painted dashes and gaps of the given lengths.
+    Dashed { dash: usize, gap: usize },
+    /// Square dots as wide as the border, separated by gaps of the same size.
+    Dotted,
+}
+
+/// Where a [`Border`] is drawn relative to the edge of the block. Borders never affect the size
+/// of the block, so an outside border extends past its bounds like a CSS `outline`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BorderPlacement {
+    /// The border lies entirely within the block, covering the edge of its background.
+    #[default]
+    Inside,
+    /// The border is centered on the edge of the block.
+    Center,
+    /// The border lies entirely outside of the block.
+    Outside,
 }
 
 impl<Child: Drawable> Block<Child> {
     pub fn new(child: Child, props: Props) -> Self {
-        Self { child, props }
+        Self {
+            child,
+            props,
+            measured: Cell::new(None),
+        }
     }
 
-    fn get_child_bounds(&self, bounds: Bounds) -> Bounds {
-        let max_child_bounds = self.calculate_max_child_bounds(bounds);
+    /// Returns the constraints set by the block's own size props. A fixed size is still kept
+    /// within the minimum and maximum, with the maximum taking precedence.
+    fn own_constraints(&self) -> Constraints {
+        let axis = |size: Option<usize>, min: Option<usize>, max: Option<usize>| {
+            let (min, max) = (min.unwrap_or(0), max.unwrap_or(usize::MAX));
+            match size {
+                Some(size) => {
+                    let size = size.max(min).min(max);
+                    (size, size)
+                }
+                None => (min, max),
+            }
+        };
+        let (min_width, max_width) =
+            axis(self.props.width, self.props.min_width, self.props.max_width);
+        let (min_height, max_height) = axis(
+            self.props.height,
+            self.props.min_height,
+            self.props.max_height,
+        );
 
-        if let Some(padding) = &self.props.padding {
-            max_child_bounds.padding_inset(&padding)
-        } else {
-            max_child_bounds
+        Constraints::new(
+            Size::new(min_width, min_height),
+            Size::new(max_width, max_height),
+        )
+    }
+
+    /// Returns the constraints of the child within the block's `constraints`, which are inset by
+    /// the padding.
+    fn child_constraints(&self, constraints: Constraints) -> Constraints {
+        match &self.props.padding {
+            Some(padding) => constraints.deflate(padding),
+            None => constraints,
         }
     }
 
-    fn calculate_max_child_bounds(&self, bounds: Bounds) -> Bounds {
-        let mut width = if let Some(width) = self.props.width {
-            width
-        } else {
-            bounds.size.width
+    /// Returns the bounds of the child when the block is drawn into `bounds`, which are as large
+    /// as the block may grow within them, inset by the padding.
+    fn child_bounds(&self, bounds: Bounds) -> Bounds {
+        let constraints = Constraints::loose(bounds.size).intersect(self.own_constraints());
+        let bounds = Bounds {
+            position: bounds.position,
+            size: constraints.max,
         };
-        if let Some(min_width) = self.props.min_width {
-            width = width.max(min_width);
-        }
-        if let Some(max_width) = self.props.max_width {
-            width = width.min(max_width);
+
+        match &self.props.padding {
+            Some(padding) => bounds.padding_inset(padding),
+            None => bounds,
         }
+    }
 
-        let mut height = if let Some(height) = self.props.height {
-            height
-        } else {
-            bounds.size.height
+    /// Returns the bounds the block takes up when drawn into `bounds` on a canvas with `scale`
+    /// pixels per point. A parent that measured the block hands it that size, such as a grown or
+    /// stretched child of a [`Layout`](super::layout::Layout), which the block then fills.
+    /// Otherwise the block is only as large as its content within `bounds`.
+    fn get_total_bounds(&self, bounds: Bounds, scale: f64) -> Bounds {
+        let size = match self.measured.get() {
+            Some(size) if size == bounds.size => size,
+            _ => self.measure(
+                bounds.position,
+                Constraints::loose(bounds.size).with_scale(scale),
+            ),
         };
-        if let Some(min_height) = self.props.min_height {
-            height = height.max(min_height);
-        }
-        if let Some(max_height) = self.props.max_height {
-            height = height.min(max_height);
+
+        Bounds {
+            position: bounds.position,
+            size,
         }
+    }
+
+    /// Returns the outline of the block's shape for the total bounds `rect`, grown outwards by
+    /// `outset` (or shrunk, if negative). Rounded corners grow and shrink along with the outline
+    /// so they stay concentric, while square corners stay square.
+    fn shape(&self, rect: RectF, outset: f64) -> Path {
+        let radii = self
+            .props
+            .corner_radius
+            .unwrap_or_default()
+            .radii()
+            .map(|radius| {
+                if radius > 0.0 {
+                    (radius + outset).max(0.0)
+                } else {
+                    0.0
+                }
+            });
 
-        Bounds::new(bounds.position.x, bounds.position.y, width, height)
+        Path::rounded_rect(rect.inset(-outset, -outset), radii)
     }
 
-    fn get_total_bounds(&self, bounds: Bounds) -> Bounds {
-        let Size {
-            width: child_width,
-            height: child_height,
-        } = self.child.content_size(self.get_child_bounds(bounds));
+    /// Draws the shadow around the total bounds `rect`.
+    fn draw_shadow(&self, canvas: &mut dyn Canvas, rect: RectF) {
+        let Some(shadow) = &self.props.shadow else {
+            return;
+        };
 
-        let mut width = if let Some(width) = self.props.width {
-            width
-        } else {
-            child_width
+        let blur_radius = shadow.blur_radius as f64;
+        let cast = self.shape(
+            rect.translate(shadow.offset_x as f64, shadow.offset_y as f64),
+            shadow.spread as f64,
+        );
+        let Some(cast_bounds) = cast.bounds() else {
+            return;
         };
-        if let Some(min_width) = self.props.min_width {
-            width = width.max(min_width);
-        }
-        if let Some(max_width) = self.props.max_width {
-            width = width.min(max_width);
-        }
 
-        let mut height = if let Some(height) = self.props.height {
-            height
-        } else {
-            child_height
+        // Clip out the block's own shape so the shadow only shows around it.
+        let mut clip = Path::rect(
+            cast_bounds
+                .inset(-blur_radius * 2.0 - 1.0, -blur_radius * 2.0 - 1.0)
+                .union(rect),
+        );
+        clip.extend(&self.shape(rect, 0.0))
+            .set_fill_rule(FillRule::EvenOdd);
+
+        canvas.save();
+        canvas.clip_path(&clip);
+        canvas.fill_shadow(&cast, shadow.color, blur_radius);
+        canvas.restore();
+    }
+
+    /// Draws the inner glow within the total bounds `rect`.
+    fn draw_inner_glow(&self, canvas: &mut dyn Canvas, rect: RectF) {
+        let Some(glow) = &self.props.inner_glow else {
+            return;
         };
-        if let Some(min_height) = self.props.min_height {
-            height = height.max(min_height);
-        }
-        if let Some(max_height) = self.props.max_height {
-            height = height.min(max_height);
+
+        let blur_radius = glow.blur_radius as f64;
+        let (dx, dy) = (glow.offset_x as f64, glow.offset_y as f64);
+
+        // The glow is the blurred area between a frame surrounding the block and a hole in the
+        // shape of the block, offset and shrunk by the spread, seen through the block's shape.
+        let margin = blur_radius * 2.0 + dx.abs() + dy.abs() + glow.spread.unsigned_abs() as f64;
+        let mut cast = Path::rect(rect.inset(-margin - 1.0, -margin - 1.0));
+        cast.extend(&self.shape(rect.translate(dx, dy), -(glow.spread as f64)))
+            .set_fill_rule(FillRule::EvenOdd);
+
+        canvas.save();
+        canvas.clip_path(&self.shape(rect, 0.0));
+        canvas.fill_shadow(&cast, glow.color, blur_radius);
+        canvas.restore();
+    }
+
+    /// Draws the border along the total bounds `rect`.
+    fn draw_border(&self, canvas: &mut dyn Canvas, rect: RectF) {
+        let Some(border) = &self.props.border else {
+            return;
+        };
+        if border.width == 0 {
+            return;
         }
 
-        width = width.min(bounds.size.width);
-        height = height.min(bounds.size.height);
+        let width = border.width as f64;
+        let outset = match border.placement {
+            BorderPlacement::Inside => -width / 2.0,
+            BorderPlacement::Center => 0.0,
+            BorderPlacement::Outside => width / 2.0,
+        };
+        let dash = match border.style {
+            BorderStyle::Solid => None,
+            BorderStyle::Dashed { dash, gap } => {
+                Some(Dash::new(vec![dash as f64, gap as f64], 0.0))
+            }
+            BorderStyle::Dotted => Some(Dash::new(vec![width, width], 0.0)),
+        };
 
-        Bounds::new(bounds.position.x, bounds.position.y, width, height)
+        canvas.save();
+        canvas.set_stroke_color(border.color);
+        canvas.set_stroke_style(&StrokeStyle {
+            width,
+            dash,
+            ..Default::default()
+        });
+        canvas.stroke_path(&self.shape(rect, outset));
+        canvas.restore();
     }
 
-    fn draw_background(
-        &self,
-        ctx: &super::Context,
-        bounds: super::geometry::Bounds,
-    ) -> Result<(), super::Error> {
-        let self_bounds = self.get_total_bounds(bounds);
+    /// Fills the total bounds `rect` with the background.
+    fn draw_background(&self, canvas: &mut dyn Canvas, rect: RectF) -> Result<(), super::Error> {
+        if let Some(background) = &self.props.background_color {
+            match background {
+                Paint::Solid(color) => canvas.set_fill_color(*color),
+                paint => canvas.set_fill_paint(paint, rect),
+            }
 
-        if let Some(bg_color) = &self.props.background_color {
-            ctx.set_fill_color(&(*bg_color).into());
-            ctx.fill_rect(self_bounds.into());
+            match self.props.corner_radius {
+                Some(radius) if !radius.is_zero() => {
+                    canvas.fill_path(&Path::rounded_rect(rect, radius.radii()))
+                }
+                _ => canvas.fill_rect(rect),
+            }
         }
 
         Ok(())
@@ -119,15 +315,534 @@ impl<Child: Drawable> Block<Child> {
 
 impl<Child: Drawable> Drawable for Block<Child> {
     fn content_size(&self, bounds: super::geometry::Bounds) -> super::geometry::Size {
-        self.get_total_bounds(bounds).size
+        self.measure(bounds.position, Constraints::loose(bounds.size))
+    }
+
+    /// The size of the child within the block's own constraints, where the size props apply
+    /// within `constraints`. Padding does not add to the size.
+    fn measure(&self, position: Point, constraints: Constraints) -> Size {
+        let constraints = constraints.intersect(self.own_constraints());
+        let size = if constraints.is_tight() {
+            constraints.min
+        } else {
+            let child_bounds = self.child_bounds(Bounds {
+                position,
+                size: constraints.max,
+            });
+            let child_size = self
+                .child
+                .measure(child_bounds.position, self.child_constraints(constraints));
+
+            constraints.constrain(child_size)
+        };
+        self.measured.set(Some(size));
+
+        size
     }
 
-    fn draw(&self, ctx: &super::Context, bounds: super::geometry::Bounds) -> UiResult<()> {
-        self.draw_background(ctx, bounds)?;
+    /// The baseline of the child, moved down by the top padding.
+    fn baseline(&self, bounds: Bounds) -> Option<f64> {
+        let child_bounds = self.child_bounds(bounds);
+        let offset = (child_bounds.position.y - bounds.position.y) as f64;
 
-        let child_bounds = self.get_child_bounds(bounds);
-        self.child.draw(ctx, child_bounds)?;
+        self.child
+            .baseline(child_bounds)
+            .map(|baseline| offset + baseline)
+    }
 
-        Ok(())
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: super::geometry::Bounds) -> UiResult<()> {
+        let rect: RectF = self.get_total_bounds(bounds, canvas.scale()).into();
+        self.draw_shadow(canvas, rect);
+        self.draw_background(canvas, rect)?;
+        self.draw_inner_glow(canvas, rect);
+        self.draw_border(canvas, rect);
+
+        let child_bounds = self.child_bounds(bounds);
+        if !self.props.clip_children {
+            return self.child.draw(canvas, child_bounds);
+        }
+
+        canvas.save();
+        canvas.clip_path(&self.shape(rect, 0.0));
+        canvas.clip_rect(child_bounds.into());
+        let result = self.child.draw(canvas, child_bounds);
+        canvas.restore();
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        canvas::recording::{DisplayItem, RecordingCanvas},
+        geometry::{PointF, RectF},
+        layout::{self, Direction, Layout},
+        paint::{Gradient, GradientKind, GradientStop},
+        shape::{self, Shape},
+        snapshot::{assert_snapshot, reference_path},
+    };
+
+    use super::*;
+
+    #[test]
+    fn background_fills_total_bounds() {
+        let block = Block::new(
+            (),
+            Props {
+                background_color: Some(Color::BLUE.into()),
+                min_width: Some(30),
+                min_height: Some(12),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &block,
+            Bounds::new(5, 4, 40, 20),
+            reference_path("block_background"),
+        );
+    }
+
+    #[test]
+    fn padding_insets_child() {
+        let inner = Block::new(
+            (),
+            Props {
+                background_color: Some(Color::BLACK.into()),
+                min_width: Some(20),
+                min_height: Some(10),
+                ..Default::default()
+            },
+        );
+        let block = Block::new(
+            inner,
+            Props {
+                background_color: Some(Color::BLUE.into()),
+                padding: Some(Padding::new(2, 4, 3, 1)),
+                min_width: Some(30),
+                min_height: Some(16),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &block,
+            Bounds::new(0, 0, 40, 20),
+            reference_path("block_padding"),
+        );
+    }
+
+    #[test]
+    fn size_props_apply_within_constraints() {
+        let fixed = Block::new(
+            (),
+            Props {
+                width: Some(20),
+                height: Some(10),
+                ..Default::default()
+            },
+        );
+        // A fixed size wins over a larger minimum, but not over a smaller maximum.
+        assert_eq!(
+            fixed.measure(Point::origin(), Constraints::tight(Size::new(40, 40))),
+            Size::new(20, 10)
+        );
+        assert_eq!(
+            fixed.measure(Point::origin(), Constraints::loose(Size::new(15, 40))),
+            Size::new(15, 10)
+        );
+
+        // Without a fixed size, the block is brought up to the minimum, and a minimum size
+        // larger than the bounds does not push the child out of them.
+        let wide = Block::new(
+            (),
+            Props {
+                min_width: Some(100),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            wide.measure(
+                Point::origin(),
+                Constraints::new(Size::new(0, 12), Size::new(40, 40))
+            ),
+            Size::new(40, 12)
+        );
+        assert_eq!(
+            wide.child_bounds(Bounds::new(0, 0, 40, 40)),
+            Bounds::new(0, 0, 40, 40)
+        );
+    }
+
+    #[test]
+    fn corner_radius_fills_rounded_path() {
+        let block = Block::new(
+            (),
+            Props {
+                background_color: Some(Color::BLUE.into()),
+                width: Some(20),
+                height: Some(10),
+                corner_radius: Some(CornerRadius::uni(4)),
+                ..Default::default()
+            },
+        );
+
+        let list = RecordingCanvas::record(&block, Bounds::new(0, 0, 40, 10)).unwrap();
+
+        assert_eq!(
+            list.items(),
+            [
+                DisplayItem::SetFillColor(Color::BLUE),
+                DisplayItem::FillPath(Path::rounded_rect(
+                    RectF::new(0.0, 0.0, 20.0, 10.0),
+                    [4.0; 4]
+                )),
+            ]
+        );
+    }
+
+    #[test]
+    fn per_corner_radius_rounds_outer_edges() {
+        let pill = |radius| {
+            Block::new(
+                (),
+                Props {
+                    background_color: Some(Color::BLACK.into()),
+                    width: Some(24),
+                    height: Some(16),
+                    corner_radius: Some(radius),
+                    ..Default::default()
+                },
+            )
+        };
+        let group = Layout::with_children(
+            vec![pill(CornerRadius::left(8)), pill(CornerRadius::right(8))],
+            layout::Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &group,
+            Bounds::new(2, 2, 48, 16),
+            reference_path("block_corner_radius"),
+        );
+    }
+
+    #[test]
+    fn border_placement_offsets_stroke() {
+        let bordered = |placement| {
+            Block::new(
+                (),
+                Props {
+                    width: Some(20),
+                    height: Some(10),
+                    corner_radius: Some(CornerRadius::uni(4)),
+                    border: Some(Border {
+                        placement,
+                        style: BorderStyle::Dashed { dash: 3, gap: 1 },
+                        ..Border::new(2, Color::RED)
+                    }),
+                    ..Default::default()
+                },
+            )
+        };
+        let bounds = Bounds::new(0, 0, 40, 10);
+        let rect = RectF::new(0.0, 0.0, 20.0, 10.0);
+
+        let inside = RecordingCanvas::record(&bordered(BorderPlacement::Inside), bounds).unwrap();
+        assert_eq!(
+            inside.items(),
+            [
+                DisplayItem::Save,
+                DisplayItem::SetStrokeColor(Color::RED),
+                DisplayItem::SetStrokeStyle(StrokeStyle {
+                    width: 2.0,
+                    dash: Some(Dash::new(vec![3.0, 1.0], 0.0)),
+                    ..Default::default()
+                }),
+                DisplayItem::StrokePath(Path::rounded_rect(rect.inset(1.0, 1.0), [3.0; 4])),
+                DisplayItem::Restore,
+            ]
+        );
+
+        let outside = RecordingCanvas::record(&bordered(BorderPlacement::Outside), bounds).unwrap();
+        assert_eq!(
+            outside.items()[3],
+            DisplayItem::StrokePath(Path::rounded_rect(rect.inset(-1.0, -1.0), [5.0; 4]))
+        );
+    }
+
+    #[test]
+    fn outlined_pill() {
+        let block = Block::new(
+            (),
+            Props {
+                background_color: Some(Color::BLACK.into()),
+                width: Some(40),
+                height: Some(16),
+                corner_radius: Some(CornerRadius::uni(8)),
+                border: Some(Border::new(2, Color::WHITE)),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &block,
+            Bounds::new(2, 2, 40, 16),
+            reference_path("block_border"),
+        );
+    }
+
+    #[test]
+    fn shadow_is_clipped_around_shape() {
+        let block = Block::new(
+            (),
+            Props {
+                width: Some(20),
+                height: Some(10),
+                shadow: Some(Shadow {
+                    offset_y: 2,
+                    spread: 1,
+                    ..Shadow::new(4, Color::BLACK)
+                }),
+                ..Default::default()
+            },
+        );
+
+        let list = RecordingCanvas::record(&block, Bounds::new(0, 0, 40, 20)).unwrap();
+        let rect = RectF::new(0.0, 0.0, 20.0, 10.0);
+
+        let DisplayItem::ClipPath(clip) = &list.items()[1] else {
+            panic!("expected a clip, got {:?}", list.items()[1]);
+        };
+        assert_eq!(clip.fill_rule(), FillRule::EvenOdd);
+        assert_eq!(
+            list.items()[2],
+            DisplayItem::FillShadow {
+                path: Path::rounded_rect(rect.translate(0.0, 2.0).inset(-1.0, -1.0), [0.0; 4]),
+                color: Color::BLACK,
+                blur_radius: 4.0,
+            }
+        );
+    }
+
+    #[test]
+    fn floating_pill_with_shadow_and_glow() {
+        let pill = Block::new(
+            (),
+            Props {
+                background_color: Some(Color::rgb(0.2, 0.2, 0.25).into()),
+                width: Some(40),
+                height: Some(16),
+                corner_radius: Some(CornerRadius::uni(8)),
+                shadow: Some(Shadow {
+                    offset_y: 2,
+                    ..Shadow::new(6, Color::rgba(0.0, 0.0, 0.0, 0.6))
+                }),
+                inner_glow: Some(Shadow::new(4, Color::rgba(1.0, 1.0, 1.0, 0.5))),
+                ..Default::default()
+            },
+        );
+        let block = Block::new(
+            pill,
+            Props {
+                background_color: Some(Color::WHITE.into()),
+                padding: Some(Padding::uni(12)),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &block,
+            Bounds::new(0, 0, 64, 40),
+            reference_path("block_shadow"),
+        );
+    }
+
+    #[test]
+    fn gradient_background_is_relative_to_block() {
+        let gradient = Gradient::linear(90.0, vec![GradientStop::new(0.0, Color::RED)]);
+        let block = Block::new(
+            (),
+            Props {
+                background_color: Some(gradient.clone().into()),
+                width: Some(20),
+                height: Some(10),
+                ..Default::default()
+            },
+        );
+
+        let list = RecordingCanvas::record(&block, Bounds::new(5, 0, 40, 10)).unwrap();
+        let rect = RectF::new(5.0, 0.0, 20.0, 10.0);
+
+        assert_eq!(
+            list.items(),
+            [
+                DisplayItem::SetFillPaint {
+                    paint: gradient.into(),
+                    area: rect,
+                },
+                DisplayItem::FillRect(rect),
+            ]
+        );
+    }
+
+    #[test]
+    fn gradient_backgrounds() {
+        let swatch = |gradient: Gradient| {
+            Block::new(
+                (),
+                Props {
+                    background_color: Some(gradient.into()),
+                    width: Some(32),
+                    height: Some(16),
+                    corner_radius: Some(CornerRadius::uni(4)),
+                    ..Default::default()
+                },
+            )
+        };
+        let colors = [
+            Color::RED,
+            Color::rgb(1.0, 0.8, 0.0),
+            Color::GREEN,
+            Color::BLUE,
+        ];
+        let group = Layout::with_children(
+            vec![
+                swatch(Gradient::evenly_spaced(
+                    GradientKind::Linear { angle: 120.0 },
+                    &colors,
+                )),
+                swatch(Gradient::radial(vec![
+                    GradientStop::new(0.0, Color::WHITE),
+                    GradientStop::new(1.0, Color::BLUE.with_alpha(0.0)),
+                ])),
+                swatch(Gradient::evenly_spaced(
+                    GradientKind::Conic {
+                        center: PointF::new(0.5, 0.5),
+                        angle: 0.0,
+                    },
+                    &[Color::RED, Color::BLUE, Color::RED],
+                )),
+            ],
+            layout::Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &group,
+            Bounds::new(0, 0, 96, 16),
+            reference_path("block_gradient"),
+        );
+    }
+
+    #[test]
+    fn clip_children_clips_to_padded_shape() {
+        let big = Path::rect(RectF::new(0.0, 0.0, 100.0, 100.0));
+        let block = Block::new(
+            Shape::new(
+                big.clone(),
+                shape::Props {
+                    fill: Some(Color::RED.into()),
+                    ..Default::default()
+                },
+            ),
+            Props {
+                width: Some(20),
+                height: Some(10),
+                padding: Some(Padding::uni(1)),
+                corner_radius: Some(CornerRadius::uni(4)),
+                clip_children: true,
+                ..Default::default()
+            },
+        );
+
+        let list = RecordingCanvas::record(&block, Bounds::new(0, 0, 40, 10)).unwrap();
+
+        assert_eq!(
+            list.items(),
+            [
+                DisplayItem::Save,
+                DisplayItem::ClipPath(Path::rounded_rect(
+                    RectF::new(0.0, 0.0, 20.0, 10.0),
+                    [4.0; 4]
+                )),
+                DisplayItem::ClipRect(RectF::new(1.0, 1.0, 18.0, 8.0)),
+                DisplayItem::Save,
+                DisplayItem::SetFillColor(Color::RED),
+                DisplayItem::FillPath(big.map_points(|p| PointF::new(p.x + 1.0, p.y + 1.0))),
+                DisplayItem::Restore,
+                DisplayItem::Restore,
+            ]
+        );
+    }
+
+    #[test]
+    fn clipped_pills() {
+        let pill = |child| {
+            Block::new(
+                child,
+                Props {
+                    background_color: Some(Color::rgb(0.2, 0.2, 0.25).into()),
+                    width: Some(40),
+                    height: Some(16),
+                    corner_radius: Some(CornerRadius::uni(8)),
+                    clip_children: true,
+                    ..Default::default()
+                },
+            )
+        };
+        let spaced = |child| {
+            Block::new(
+                child,
+                Props {
+                    width: Some(48),
+                    padding: Some(Padding::new(0, 8, 0, 0)),
+                    ..Default::default()
+                },
+            )
+        };
+        let oversized = |padding| {
+            Block::new(
+                Shape::new(
+                    Path::circle(PointF::new(28.0, 12.0), 16.0),
+                    shape::Props {
+                        fill: Some(
+                            Gradient::evenly_spaced(
+                                GradientKind::Linear { angle: 45.0 },
+                                &[Color::RED, Color::rgb(1.0, 0.8, 0.0)],
+                            )
+                            .into(),
+                        ),
+                        ..Default::default()
+                    },
+                ),
+                Props {
+                    padding: Some(padding),
+                    ..Default::default()
+                },
+            )
+        };
+        let group = Layout::with_children(
+            vec![
+                spaced(pill(oversized(Padding::new(4, 0, 0, 0)))),
+                spaced(pill(oversized(Padding::uni(3)))),
+            ],
+            layout::Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &group,
+            Bounds::new(0, 0, 96, 16),
+            reference_path("block_clip_children"),
+        );
     }
 }
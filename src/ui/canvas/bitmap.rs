@@ -0,0 +1,169 @@
+use std::path::Path;
+
+use tiny_skia::Pixmap;
+use zune_jpeg::{
+    zune_core::{colorspace::ColorSpace, options::DecoderOptions},
+    JpegDecoder,
+};
+
+use crate::ui::{color::Color, Error, UiResult};
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+const JPEG_SIGNATURE: &[u8] = &[0xff, 0xd8, 0xff];
+
+/// An owned raster image stored as premultiplied 8-bit RGBA pixels, row by row, starting from the
+/// top-left corner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bitmap {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+impl Bitmap {
+    /// Creates a bitmap from premultiplied RGBA pixel data.
+    ///
+    /// Returns [`None`] if `data` does not hold exactly `width * height` pixels.
+    pub fn from_premultiplied_rgba(width: u32, height: u32, data: Vec<u8>) -> Option<Self> {
+        if data.len() != width as usize * height as usize * 4 {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            data,
+        })
+    }
+
+    /// Creates a bitmap from straight (non-premultiplied) RGBA pixel data, as produced by most
+    /// image decoders.
+    ///
+    /// Returns [`None`] if `data` does not hold exactly `width * height` pixels.
+    pub fn from_rgba(width: u32, height: u32, mut data: Vec<u8>) -> Option<Self> {
+        for pixel in data.chunks_exact_mut(4) {
+            let alpha = pixel[3] as u16;
+            for channel in &mut pixel[..3] {
+                *channel = ((*channel as u16 * alpha + 127) / 255) as u8;
+            }
+        }
+
+        Self::from_premultiplied_rgba(width, height, data)
+    }
+
+    /// Decodes a PNG or JPEG image, detecting the format from its contents.
+    pub fn decode(bytes: &[u8]) -> UiResult<Self> {
+        if bytes.starts_with(PNG_SIGNATURE) {
+            let pixmap =
+                Pixmap::decode_png(bytes).map_err(|err| Error::ImageDecoding(err.to_string()))?;
+
+            Ok(Self {
+                width: pixmap.width(),
+                height: pixmap.height(),
+                data: pixmap.take(),
+            })
+        } else if bytes.starts_with(JPEG_SIGNATURE) {
+            let options = DecoderOptions::default().jpeg_set_out_colorspace(ColorSpace::RGBA);
+            let mut decoder = JpegDecoder::new_with_options(bytes, options);
+            let pixels = decoder
+                .decode()
+                .map_err(|err| Error::ImageDecoding(err.to_string()))?;
+            let info = decoder
+                .info()
+                .ok_or_else(|| Error::ImageDecoding("missing JPEG header".to_string()))?;
+
+            // JPEGs have no transparency, so the pixels are already premultiplied.
+            Self::from_premultiplied_rgba(info.width as u32, info.height as u32, pixels)
+                .ok_or_else(|| Error::ImageDecoding("unexpected JPEG pixel layout".to_string()))
+        } else {
+            Err(Error::ImageDecoding(
+                "unsupported image format, expected PNG or JPEG".to_string(),
+            ))
+        }
+    }
+
+    /// Reads and decodes the PNG or JPEG image at `path`.
+    pub fn load(path: impl AsRef<Path>) -> UiResult<Self> {
+        let bytes = std::fs::read(path).map_err(|err| Error::ImageDecoding(err.to_string()))?;
+
+        Self::decode(&bytes)
+    }
+
+    /// Returns a copy of the bitmap where every pixel is `color`, keeping only the transparency
+    /// of the original pixels. Useful for recoloring monochrome icons.
+    pub fn tinted(&self, color: Color) -> Self {
+        let [r, g, b, a] = color.components().map(|c| c.clamp(0.0, 1.0));
+        let data = self
+            .data
+            .chunks_exact(4)
+            .flat_map(|pixel| {
+                let alpha = a * pixel[3] as f64 / 255.0;
+                [r * alpha, g * alpha, b * alpha, alpha].map(|c| (c * 255.0).round() as u8)
+            })
+            .collect();
+
+        Self { data, ..*self }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the premultiplied RGBA value of the pixel at `x`, `y`, or [`None`] if it lies
+    /// outside of the bitmap.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let offset = (y as usize * self.width as usize + x as usize) * 4;
+        self.data[offset..offset + 4].try_into().ok()
+    }
+
+    /// Returns the premultiplied RGBA pixel data.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_png() {
+        let mut pixmap = Pixmap::new(2, 1).unwrap();
+        pixmap.pixels_mut()[1] = tiny_skia::PremultipliedColorU8::from_rgba(0, 64, 0, 128).unwrap();
+        let png = pixmap.encode_png().unwrap();
+
+        let bitmap = Bitmap::decode(&png).unwrap();
+        assert_eq!((bitmap.width(), bitmap.height()), (2, 1));
+        assert_eq!(bitmap.pixel(0, 0), Some([0, 0, 0, 0]));
+        assert_eq!(bitmap.pixel(1, 0), Some([0, 64, 0, 128]));
+    }
+
+    #[test]
+    fn rejects_unknown_and_corrupt_images() {
+        assert!(matches!(
+            Bitmap::decode(b"GIF89a"),
+            Err(Error::ImageDecoding(_))
+        ));
+        assert!(matches!(
+            Bitmap::decode(&[0xff, 0xd8, 0xff, 0xe0, 0x00]),
+            Err(Error::ImageDecoding(_))
+        ));
+    }
+
+    #[test]
+    fn tint_keeps_alpha() {
+        let bitmap = Bitmap::from_rgba(2, 1, vec![255, 255, 255, 255, 10, 20, 30, 51]).unwrap();
+        let tinted = bitmap.tinted(Color::RED);
+
+        assert_eq!(tinted.pixel(0, 0), Some([255, 0, 0, 255]));
+        assert_eq!(tinted.pixel(1, 0), Some([51, 0, 0, 51]));
+    }
+}
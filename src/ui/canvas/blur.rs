@@ -0,0 +1,182 @@
+//! CPU implementation of blurred fills, shared by backends that lack a suitable native primitive.
+
+use tiny_skia::{FillRule as SkFillRule, Mask, Transform};
+
+use crate::ui::{color::Color, geometry::RectF};
+
+use super::{raster::RasterCanvas, Bitmap, FillRule, Path};
+
+/// Number of successive box blurs used to approximate a Gaussian blur.
+const BOX_PASSES: usize = 3;
+
+/// Rasterizes `path` filled with `color` and blurred by a Gaussian with a standard deviation of
+/// `blur_radius / 2`, at `scale` pixels per point.
+///
+/// Returns the blurred image along with the rectangle, in points, that it should be drawn into,
+/// or [`None`] if the path is empty or the result would be too large to allocate.
+pub(crate) fn blurred_path(
+    path: &Path,
+    color: Color,
+    blur_radius: f64,
+    scale: f64,
+) -> Option<(Bitmap, RectF)> {
+    let path_bounds = path.bounds()?;
+    let sigma = (blur_radius.max(0.0) / 2.0) * scale;
+    // Three standard deviations covers all but a negligible part of the blurred edge.
+    let margin = (sigma * 3.0).ceil() + 1.0;
+
+    let left = (path_bounds.min_x() * scale - margin).floor();
+    let top = (path_bounds.min_y() * scale - margin).floor();
+    let width = (path_bounds.max_x() * scale + margin).ceil() - left;
+    let height = (path_bounds.max_y() * scale + margin).ceil() - top;
+    let (width, height) = (width as u32, height as u32);
+
+    let mut mask = Mask::new(width, height)?;
+    let sk_path = RasterCanvas::build_path(path)?;
+    let fill_rule = match path.fill_rule() {
+        FillRule::NonZero => SkFillRule::Winding,
+        FillRule::EvenOdd => SkFillRule::EvenOdd,
+    };
+    mask.fill_path(
+        &sk_path,
+        fill_rule,
+        true,
+        Transform::from_row(
+            scale as f32,
+            0.0,
+            0.0,
+            scale as f32,
+            -left as f32,
+            -top as f32,
+        ),
+    );
+
+    let mut alpha = mask.data().to_vec();
+    for size in box_sizes(sigma) {
+        let radius = size / 2;
+        box_blur_rows(&mut alpha, width as usize, height as usize, radius);
+        transpose(&mut alpha, width as usize, height as usize);
+        box_blur_rows(&mut alpha, height as usize, width as usize, radius);
+        transpose(&mut alpha, height as usize, width as usize);
+    }
+
+    let [r, g, b, a] = color.components().map(|c| c.clamp(0.0, 1.0));
+    let pixels = alpha
+        .iter()
+        .flat_map(|coverage| {
+            let alpha = a * (*coverage as f64 / 255.0);
+            [r * alpha, g * alpha, b * alpha, alpha].map(|c| (c * 255.0).round() as u8)
+        })
+        .collect();
+
+    let bitmap = Bitmap::from_premultiplied_rgba(width, height, pixels)?;
+    let rect = RectF::new(
+        left / scale,
+        top / scale,
+        width as f64 / scale,
+        height as f64 / scale,
+    );
+
+    Some((bitmap, rect))
+}
+
+/// Returns the widths of the box blurs which, applied in sequence, approximate a Gaussian blur
+/// with the standard deviation `sigma`.
+///
+/// See <https://www.peterkovesi.com/papers/FastGaussianSmoothing.pdf>.
+fn box_sizes(sigma: f64) -> [usize; BOX_PASSES] {
+    let n = BOX_PASSES as f64;
+    let ideal = (12.0 * sigma * sigma / n + 1.0).sqrt();
+    let mut lower = ideal.floor() as usize;
+    if lower.is_multiple_of(2) {
+        lower = lower.saturating_sub(1);
+    }
+    let lower = lower.max(1);
+    let upper = lower + 2;
+
+    let l = lower as f64;
+    let lower_count =
+        ((12.0 * sigma * sigma - n * l * l - 4.0 * n * l - 3.0 * n) / (-4.0 * l - 4.0)).round();
+
+    std::array::from_fn(|i| {
+        if (i as f64) < lower_count {
+            lower
+        } else {
+            upper
+        }
+    })
+}
+
+/// Replaces every value with the average of the `2 * radius + 1` values around it on the same
+/// row, treating values outside of the buffer as zero.
+fn box_blur_rows(data: &mut [u8], width: usize, height: usize, radius: usize) {
+    if radius == 0 {
+        return;
+    }
+
+    let size = (radius * 2 + 1) as u32;
+    let mut row = vec![0u8; width];
+
+    for y in 0..height {
+        let line = &mut data[y * width..(y + 1) * width];
+        row.copy_from_slice(line);
+
+        let mut sum: u32 = row.iter().take(radius + 1).map(|v| *v as u32).sum();
+        for x in 0..width {
+            line[x] = ((sum + size / 2) / size) as u8;
+
+            if let Some(incoming) = row.get(x + radius + 1) {
+                sum += *incoming as u32;
+            }
+            if x >= radius {
+                sum -= row[x - radius] as u32;
+            }
+        }
+    }
+}
+
+/// Transposes a row-major `width` by `height` buffer in place, so that it becomes a row-major
+/// `height` by `width` buffer.
+fn transpose(data: &mut [u8], width: usize, height: usize) {
+    let source = data.to_vec();
+    for y in 0..height {
+        for x in 0..width {
+            data[x * height + y] = source[y * width + x];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn box_sizes_grow_with_sigma() {
+        assert_eq!(box_sizes(0.0), [1, 1, 1]);
+        assert_eq!(box_sizes(2.0), [3, 3, 5]);
+    }
+
+    #[test]
+    fn blur_preserves_total_coverage() {
+        let path = Path::rect(RectF::new(0.0, 0.0, 10.0, 10.0));
+        let (bitmap, rect) = blurred_path(&path, Color::BLACK, 4.0, 1.0).unwrap();
+
+        assert!(rect.min_x() < 0.0 && rect.max_x() > 10.0);
+
+        let total: u32 = bitmap.data().chunks(4).map(|pixel| pixel[3] as u32).sum();
+        let expected = 100 * 255;
+        assert!(
+            total.abs_diff(expected) < expected / 50,
+            "total coverage {total}"
+        );
+
+        // The center stays solid while the edge is softened.
+        let center = bitmap
+            .pixel(bitmap.width() / 2, bitmap.height() / 2)
+            .unwrap();
+        let origin = (-rect.min_x()) as u32;
+        let edge = bitmap.pixel(origin, bitmap.height() / 2).unwrap();
+        assert_eq!(center[3], 255);
+        assert!(edge[3] > 64 && edge[3] < 192, "edge alpha {}", edge[3]);
+    }
+}
@@ -0,0 +1,288 @@
+use std::sync::Arc;
+
+use core_graphics::{
+    color_space::CGColorSpace,
+    context::{CGContext, CGContextRef, CGLineCap, CGLineJoin},
+    data_provider::CGDataProvider,
+    geometry::CGPoint,
+    gradient::{CGGradient, CGGradientDrawingOptions},
+    image::CGImage,
+    sys::CGContextRef as CGContextRefSys,
+};
+use libc::c_void;
+
+use crate::ui::{
+    color::Color,
+    geometry::{PointF, RectF},
+    paint::{self, Gradient, GradientKind, Paint},
+};
+
+use super::{
+    blur, gradient, Bitmap, Canvas, FillRule, LineCap, LineJoin, Path, PathElement, StrokeStyle,
+};
+
+/// `kCGImageAlphaPremultipliedLast | kCGBitmapByteOrder32Big`, matching the layout of [`Bitmap`].
+const BITMAP_INFO_PREMULTIPLIED_RGBA: u32 = 1 | (4 << 12);
+/// `kCGRenderingIntentDefault`
+const RENDERING_INTENT_DEFAULT: u32 = 0;
+
+// Transparency layers are not wrapped by the `core-graphics` crate.
+#[link(name = "CoreGraphics", kind = "framework")]
+extern "C" {
+    fn CGContextBeginTransparencyLayer(c: CGContextRefSys, auxiliary_info: *const c_void);
+    fn CGContextEndTransparencyLayer(c: CGContextRefSys);
+}
+
+/// A [`Canvas`] backed by a CoreGraphics context, such as the one attached to an
+/// [`SlsWindow`](crate::ffi::sls::SlsWindow).
+///
+/// CoreGraphics places the origin in the bottom-left corner, so the context is flipped for the
+/// lifetime of the canvas and restored once it is dropped.
+pub struct CgCanvas<'ctx> {
+    ctx: &'ctx CGContext,
+    /// Gradient to fill shapes with, along with the area it is relative to. CoreGraphics has no
+    /// notion of a gradient fill, so it is tracked here alongside the context's state stack.
+    fill_gradient: Option<(Gradient, RectF)>,
+    stack: Vec<Option<(Gradient, RectF)>>,
+}
+
+impl<'ctx> CgCanvas<'ctx> {
+    /// Wraps `ctx`, whose drawable area is `height` points tall.
+    pub fn new(ctx: &'ctx CGContext, height: f64) -> Self {
+        ctx.save();
+        ctx.translate(0.0, height);
+        ctx.scale(1.0, -1.0);
+
+        Self {
+            ctx,
+            fill_gradient: None,
+            stack: Vec::new(),
+        }
+    }
+
+    /// Returns the raw pointer to the context, for calling into CoreGraphics directly.
+    fn raw_ctx(&self) -> CGContextRefSys {
+        let ctx: &CGContextRef = self.ctx;
+        ctx as *const CGContextRef as CGContextRefSys
+    }
+
+    fn clip_to_traced_path(&self, fill_rule: FillRule) {
+        match fill_rule {
+            FillRule::NonZero => self.ctx.clip(),
+            FillRule::EvenOdd => self.ctx.eo_clip(),
+        }
+    }
+
+    /// Fills `path` with `gradient`, which is relative to `area`.
+    fn fill_path_with_gradient(&mut self, path: &Path, gradient: &Gradient, area: RectF) {
+        if gradient.stops.is_empty() {
+            return;
+        }
+
+        self.ctx.save();
+        self.trace_path(path);
+        self.clip_to_traced_path(path.fill_rule());
+
+        let (components, locations): (Vec<_>, Vec<_>) = gradient
+            .stops
+            .iter()
+            .map(|stop| (stop.color.components(), stop.offset))
+            .unzip();
+        let cg_gradient = CGGradient::create_with_color_components(
+            &CGColorSpace::create_device_rgb(),
+            components.as_flattened(),
+            &locations,
+            locations.len(),
+        );
+        let options = CGGradientDrawingOptions::CGGradientDrawsBeforeStartLocation
+            | CGGradientDrawingOptions::CGGradientDrawsAfterEndLocation;
+        let point = |p: PointF| CGPoint::new(p.x, p.y);
+
+        match gradient.kind {
+            GradientKind::Linear { angle } => {
+                let (start, end) = paint::linear_endpoints(angle, area);
+                self.ctx
+                    .draw_linear_gradient(&cg_gradient, point(start), point(end), options);
+            }
+            GradientKind::Radial { center, radius } => {
+                let (center, radius) = paint::radial_circle(center, radius, area);
+                self.ctx.draw_radial_gradient(
+                    &cg_gradient,
+                    point(center),
+                    0.0,
+                    point(center),
+                    radius,
+                    options,
+                );
+            }
+            // CoreGraphics only supports conic gradients from macOS 14, so sample on the CPU.
+            GradientKind::Conic { .. } => {
+                if let Some(bitmap) = gradient::rasterize(gradient, area, self.scale()) {
+                    self.draw_image(&bitmap, area);
+                }
+            }
+        }
+
+        self.ctx.restore();
+    }
+
+    fn trace_path(&self, path: &Path) {
+        self.ctx.begin_path();
+        for element in path.elements() {
+            match *element {
+                PathElement::MoveTo(p) => self.ctx.move_to_point(p.x, p.y),
+                PathElement::LineTo(p) => self.ctx.add_line_to_point(p.x, p.y),
+                PathElement::QuadTo(c, p) => self.ctx.add_quad_curve_to_point(c.x, c.y, p.x, p.y),
+                PathElement::CubicTo(c1, c2, p) => self
+                    .ctx
+                    .add_curve_to_point(c1.x, c1.y, c2.x, c2.y, p.x, p.y),
+                PathElement::Close => self.ctx.close_path(),
+            }
+        }
+    }
+}
+
+impl Drop for CgCanvas<'_> {
+    fn drop(&mut self) {
+        self.ctx.restore();
+    }
+}
+
+impl Canvas for CgCanvas<'_> {
+    fn save(&mut self) {
+        self.ctx.save();
+        self.stack.push(self.fill_gradient.clone());
+    }
+
+    fn restore(&mut self) {
+        self.ctx.restore();
+        if let Some(fill_gradient) = self.stack.pop() {
+            self.fill_gradient = fill_gradient;
+        }
+    }
+
+    fn set_fill_color(&mut self, color: Color) {
+        self.fill_gradient = None;
+        self.ctx.set_fill_color(&color.into());
+    }
+
+    fn set_fill_paint(&mut self, paint: &Paint, area: RectF) {
+        match paint {
+            Paint::Solid(color) => self.set_fill_color(*color),
+            Paint::Gradient(gradient) => self.fill_gradient = Some((gradient.clone(), area)),
+        }
+    }
+
+    fn set_stroke_color(&mut self, color: Color) {
+        let [r, g, b, a] = color.components();
+        self.ctx.set_rgb_stroke_color(r, g, b, a);
+    }
+
+    fn set_stroke_style(&mut self, style: &StrokeStyle) {
+        self.ctx.set_line_width(style.width);
+        self.ctx.set_line_cap(match style.cap {
+            LineCap::Butt => CGLineCap::CGLineCapButt,
+            LineCap::Round => CGLineCap::CGLineCapRound,
+            LineCap::Square => CGLineCap::CGLineCapSquare,
+        });
+        self.ctx.set_line_join(match style.join {
+            LineJoin::Miter => CGLineJoin::CGLineJoinMiter,
+            LineJoin::Round => CGLineJoin::CGLineJoinRound,
+            LineJoin::Bevel => CGLineJoin::CGLineJoinBevel,
+        });
+        self.ctx.set_miter_limit(style.miter_limit);
+
+        match &style.dash {
+            Some(dash) => self.ctx.set_line_dash(dash.offset, &dash.lengths),
+            None => self.ctx.set_line_dash(0.0, &[]),
+        }
+    }
+
+    fn fill_rect(&mut self, rect: RectF) {
+        if self.fill_gradient.is_some() {
+            return self.fill_path(&Path::rect(rect));
+        }
+
+        self.ctx.fill_rect(rect.into());
+    }
+
+    fn stroke_rect(&mut self, rect: RectF) {
+        self.ctx.stroke_rect(rect.into());
+    }
+
+    fn fill_path(&mut self, path: &Path) {
+        if let Some((gradient, area)) = self.fill_gradient.clone() {
+            return self.fill_path_with_gradient(path, &gradient, area);
+        }
+
+        self.trace_path(path);
+        match path.fill_rule() {
+            FillRule::NonZero => self.ctx.fill_path(),
+            FillRule::EvenOdd => self.ctx.eo_fill_path(),
+        }
+    }
+
+    fn stroke_path(&mut self, path: &Path) {
+        self.trace_path(path);
+        self.ctx.stroke_path();
+    }
+
+    fn clip_rect(&mut self, rect: RectF) {
+        self.ctx.clip_to_rect(rect.into());
+    }
+
+    fn clip_path(&mut self, path: &Path) {
+        self.trace_path(path);
+        self.clip_to_traced_path(path.fill_rule());
+    }
+
+    fn draw_image(&mut self, image: &Bitmap, rect: RectF) {
+        let provider = CGDataProvider::from_buffer(Arc::new(image.data().to_vec()));
+        let image = CGImage::new(
+            image.width() as usize,
+            image.height() as usize,
+            8,
+            32,
+            image.width() as usize * 4,
+            &CGColorSpace::create_device_rgb(),
+            BITMAP_INFO_PREMULTIPLIED_RGBA,
+            &provider,
+            true,
+            RENDERING_INTENT_DEFAULT,
+        );
+
+        // Images are drawn bottom-up, so undo the flip applied in [`CgCanvas::new`] locally.
+        self.ctx.save();
+        self.ctx.translate(rect.x, rect.y + rect.height);
+        self.ctx.scale(1.0, -1.0);
+        self.ctx
+            .draw_image(RectF::new(0.0, 0.0, rect.width, rect.height).into(), &image);
+        self.ctx.restore();
+    }
+
+    fn fill_shadow(&mut self, path: &Path, color: Color, blur_radius: f64) {
+        // CoreGraphics shadows are offset in device space and always include the shape casting
+        // them, so blur on the CPU instead, at the resolution of the underlying context.
+        if let Some((bitmap, rect)) = blur::blurred_path(path, color, blur_radius, self.scale()) {
+            self.draw_image(&bitmap, rect);
+        }
+    }
+
+    fn begin_layer(&mut self, alpha: f64) {
+        // The layer is composited with the alpha in effect when it begins, while drawing inside
+        // of it starts out fully opaque again.
+        self.save();
+        self.ctx.set_alpha(alpha.clamp(0.0, 1.0));
+        unsafe { CGContextBeginTransparencyLayer(self.raw_ctx(), std::ptr::null()) };
+    }
+
+    fn end_layer(&mut self) {
+        unsafe { CGContextEndTransparencyLayer(self.raw_ctx()) };
+        self.restore();
+    }
+
+    fn scale(&self) -> f64 {
+        let ctm = self.ctx.get_ctm();
+        (ctm.a * ctm.a + ctm.b * ctm.b).sqrt()
+    }
+}
@@ -0,0 +1,35 @@
+//! CPU rasterization of gradients, shared by backends that cannot draw some of them natively.
+
+use crate::ui::{
+    geometry::{PointF, RectF},
+    paint::Gradient,
+};
+
+use super::Bitmap;
+
+/// Samples `gradient` painted over `area` into a bitmap covering `area` at `scale` pixels per
+/// point, or returns [`None`] if the area is empty.
+pub(crate) fn rasterize(gradient: &Gradient, area: RectF, scale: f64) -> Option<Bitmap> {
+    let width = (area.width * scale).ceil() as u32;
+    let height = (area.height * scale).ceil() as u32;
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let mut pixels = Vec::with_capacity(width as usize * height as usize * 4);
+    for y in 0..height {
+        for x in 0..width {
+            // Sample the center of each pixel.
+            let point = PointF::new(
+                area.x + (x as f64 + 0.5) / scale,
+                area.y + (y as f64 + 0.5) / scale,
+            );
+            let color = gradient.color_at(gradient.offset_at(point, area));
+            let [r, g, b, a] = color.components().map(|c| c.clamp(0.0, 1.0));
+
+            pixels.extend([r * a, g * a, b * a, a].map(|c| (c * 255.0).round() as u8));
+        }
+    }
+
+    Bitmap::from_premultiplied_rgba(width, height, pixels)
+}
@@ -0,0 +1,158 @@
+//! Backend-agnostic drawing surface used by every [`Drawable`](super::Drawable).
+//!
+//! All coordinates are expressed in points with the origin in the top-left corner of the surface
+//! and the y axis growing downwards, matching [`Bounds`](super::geometry::Bounds). Backends whose
+//! native coordinate system differs (such as CoreGraphics) are responsible for flipping.
+
+pub use self::{
+    bitmap::Bitmap,
+    path::{FillRule, Path, PathElement},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    color::Color,
+    font::Font,
+    geometry::{PointF, RectF},
+    paint::Paint,
+};
+
+pub mod bitmap;
+mod blur;
+#[cfg(target_os = "macos")]
+pub mod cg;
+mod gradient;
+pub mod path;
+pub mod raster;
+pub mod recording;
+
+/// Describes how paths are outlined by [`Canvas::stroke_path`] and [`Canvas::stroke_rect`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StrokeStyle {
+    pub width: f64,
+    /// Dash pattern of the stroke, or [`None`] for a solid line.
+    pub dash: Option<Dash>,
+    /// Shape of the ends of open subpaths and dashes.
+    pub cap: LineCap,
+    /// Shape of the corners between segments.
+    pub join: LineJoin,
+    /// Limit on the ratio between the length of a miter join and the stroke width, past which
+    /// the corner is beveled instead.
+    pub miter_limit: f64,
+}
+
+impl Default for StrokeStyle {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            dash: None,
+            cap: LineCap::default(),
+            join: LineJoin::default(),
+            miter_limit: 4.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LineCap {
+    /// The stroke ends exactly at the end point.
+    #[default]
+    Butt,
+    /// The stroke ends in a half circle around the end point.
+    Round,
+    /// The stroke extends past the end point by half its width.
+    Square,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LineJoin {
+    /// The outer edges of the segments are extended until they meet in a point.
+    #[default]
+    Miter,
+    /// The corner is rounded off with a circular arc.
+    Round,
+    /// The corner is cut off straight.
+    Bevel,
+}
+
+/// A stroke dash pattern.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dash {
+    /// Alternating lengths of painted and unpainted segments, starting with a painted one. An odd
+    /// number of lengths is repeated to form an even pattern, as in SVG.
+    pub lengths: Vec<f64>,
+    /// Distance into the pattern at which the stroke starts.
+    pub offset: f64,
+}
+
+impl Dash {
+    pub fn new(lengths: Vec<f64>, offset: f64) -> Self {
+        Self { lengths, offset }
+    }
+}
+
+/// A surface that drawables render onto.
+///
+/// The canvas keeps a graphics state made up of the current fill paint, stroke color, stroke
+/// style and clip. [`Canvas::save`] pushes a copy of that state onto a stack and
+/// [`Canvas::restore`] pops it again, so drawables should wrap any state changes they do not want
+/// to leak to their siblings in a save/restore pair. [`Canvas::begin_layer`] and
+/// [`Canvas::end_layer`] work the same way, but additionally group what is drawn in between.
+pub trait Canvas {
+    /// Pushes a copy of the current graphics state onto the state stack.
+    fn save(&mut self);
+
+    /// Restores the graphics state most recently pushed by [`Canvas::save`].
+    fn restore(&mut self);
+
+    /// Fills subsequent shapes with a solid `color`.
+    fn set_fill_color(&mut self, color: Color);
+
+    /// Fills subsequent shapes with `paint`. The geometry of gradients is relative to `area`,
+    /// which is usually the bounds of the shape being filled, regardless of the shape itself.
+    fn set_fill_paint(&mut self, paint: &Paint, area: RectF);
+
+    fn set_stroke_color(&mut self, color: Color);
+
+    fn set_stroke_style(&mut self, style: &StrokeStyle);
+
+    fn fill_rect(&mut self, rect: RectF);
+
+    fn stroke_rect(&mut self, rect: RectF);
+
+    fn fill_path(&mut self, path: &Path);
+
+    fn stroke_path(&mut self, path: &Path);
+
+    /// Intersects the current clip with `rect`.
+    fn clip_rect(&mut self, rect: RectF);
+
+    /// Intersects the current clip with the area enclosed by `path`.
+    fn clip_path(&mut self, path: &Path);
+
+    /// Draws `image`, scaled to fill `rect`.
+    fn draw_image(&mut self, image: &Bitmap, rect: RectF);
+
+    /// Fills `path` with `color`, softened by a Gaussian blur whose standard deviation is half of
+    /// `blur_radius` (as in CSS). This is the building block for shadows and glows.
+    fn fill_shadow(&mut self, path: &Path, color: Color, blur_radius: f64);
+
+    /// Saves the graphics state like [`Canvas::save`] and starts a transparency layer. Everything
+    /// drawn until the matching [`Canvas::end_layer`] is composited onto the canvas as a single
+    /// image, with its opacity multiplied by `alpha`.
+    fn begin_layer(&mut self, alpha: f64);
+
+    /// Composites the layer started by the most recent [`Canvas::begin_layer`] and restores the
+    /// graphics state saved along with it.
+    fn end_layer(&mut self);
+
+    /// Number of device pixels per point, e.g. `2.0` on a Retina display.
+    fn scale(&self) -> f64;
+
+    /// Fills the glyphs of `text` set in `font`, with the left end of its baseline at `origin` and
+    /// `letter_spacing` extra points between consecutive characters.
+    fn fill_text(&mut self, text: &str, origin: PointF, font: &Font, letter_spacing: f64) {
+        self.fill_path(&font.text_path(text, origin, letter_spacing));
+    }
+}
@@ -0,0 +1,446 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ui::geometry::{PointF, RectF};
+
+/// Distance of the control points from the corner of a quarter circle approximated by a cubic
+/// Bézier curve, as a fraction of the radius.
+const KAPPA: f64 = 0.552_284_749_830_793_4;
+
+/// A single segment of a [`Path`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PathElement {
+    /// Starts a new subpath at the given point.
+    MoveTo(PointF),
+    /// Adds a straight line from the current point.
+    LineTo(PointF),
+    /// Adds a quadratic Bézier curve using the control point and end point.
+    QuadTo(PointF, PointF),
+    /// Adds a cubic Bézier curve using the two control points and end point.
+    CubicTo(PointF, PointF, PointF),
+    /// Closes the current subpath with a straight line back to its starting point.
+    Close,
+}
+
+/// Determines which areas enclosed by a [`Path`] count as its inside when it is filled or used as
+/// a clip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FillRule {
+    /// A point is inside if the path winds around it a non-zero number of times.
+    #[default]
+    NonZero,
+    /// A point is inside if a ray from it crosses the path an odd number of times, which allows
+    /// cutting holes using subpaths of any direction.
+    EvenOdd,
+}
+
+/// A vector path made up of one or more subpaths, which can be filled, stroked or used as a clip
+/// by a [`Canvas`](super::Canvas).
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Path {
+    elements: Vec<PathElement>,
+    fill_rule: FillRule,
+}
+
+impl Path {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a closed path tracing the outline of `rect`.
+    pub fn rect(rect: RectF) -> Self {
+        let mut path = Self::new();
+        path.move_to(rect.min_x(), rect.min_y())
+            .line_to(rect.max_x(), rect.min_y())
+            .line_to(rect.max_x(), rect.max_y())
+            .line_to(rect.min_x(), rect.max_y())
+            .close();
+
+        path
+    }
+
+    /// Creates a closed path tracing the outline of `rect` with rounded corners. `radii` lists the
+    /// radius of each corner clockwise starting from the top-left one.
+    ///
+    /// Like CSS `border-radius`, if adjacent radii add up to more than the length of the side
+    /// between them, all radii are scaled down proportionally until they fit.
+    pub fn rounded_rect(rect: RectF, radii: [f64; 4]) -> Self {
+        let radii = radii.map(|radius| radius.max(0.0));
+        let [top_left, top_right, bottom_right, bottom_left] = radii;
+
+        let scale = [
+            (rect.width, top_left + top_right),
+            (rect.height, top_right + bottom_right),
+            (rect.width, bottom_right + bottom_left),
+            (rect.height, bottom_left + top_left),
+        ]
+        .into_iter()
+        .filter(|(_, sum)| *sum > 0.0)
+        .map(|(side, sum)| side / sum)
+        .fold(1.0f64, f64::min);
+        let [top_left, top_right, bottom_right, bottom_left] = radii.map(|radius| radius * scale);
+
+        let (left, top, right, bottom) = (rect.min_x(), rect.min_y(), rect.max_x(), rect.max_y());
+        let mut path = Self::new();
+        path.move_to(left + top_left, top)
+            .line_to(right - top_right, top)
+            .corner_to(right, top, right, top + top_right)
+            .line_to(right, bottom - bottom_right)
+            .corner_to(right, bottom, right - bottom_right, bottom)
+            .line_to(left + bottom_left, bottom)
+            .corner_to(left, bottom, left, bottom - bottom_left)
+            .line_to(left, top + top_left)
+            .corner_to(left, top, left + top_left, top)
+            .close();
+
+        path
+    }
+
+    /// Creates a closed path tracing the ellipse inscribed in `rect`.
+    pub fn ellipse(rect: RectF) -> Self {
+        let center = rect.center();
+        let (left, top, right, bottom) = (rect.min_x(), rect.min_y(), rect.max_x(), rect.max_y());
+        let mut path = Self::new();
+        path.move_to(right, center.y)
+            .corner_to(right, bottom, center.x, bottom)
+            .corner_to(left, bottom, left, center.y)
+            .corner_to(left, top, center.x, top)
+            .corner_to(right, top, right, center.y)
+            .close();
+
+        path
+    }
+
+    /// Creates a closed path tracing a circle.
+    pub fn circle(center: PointF, radius: f64) -> Self {
+        Self::ellipse(RectF::new(
+            center.x - radius,
+            center.y - radius,
+            radius * 2.0,
+            radius * 2.0,
+        ))
+    }
+
+    pub fn move_to(&mut self, x: f64, y: f64) -> &mut Self {
+        self.elements.push(PathElement::MoveTo(PointF::new(x, y)));
+        self
+    }
+
+    pub fn line_to(&mut self, x: f64, y: f64) -> &mut Self {
+        self.elements.push(PathElement::LineTo(PointF::new(x, y)));
+        self
+    }
+
+    pub fn quad_to(&mut self, cx: f64, cy: f64, x: f64, y: f64) -> &mut Self {
+        self.elements
+            .push(PathElement::QuadTo(PointF::new(cx, cy), PointF::new(x, y)));
+        self
+    }
+
+    pub fn cubic_to(
+        &mut self,
+        c1x: f64,
+        c1y: f64,
+        c2x: f64,
+        c2y: f64,
+        x: f64,
+        y: f64,
+    ) -> &mut Self {
+        self.elements.push(PathElement::CubicTo(
+            PointF::new(c1x, c1y),
+            PointF::new(c2x, c2y),
+            PointF::new(x, y),
+        ));
+        self
+    }
+
+    /// Adds a quarter-ellipse from the current point to `x`, `y`, bending towards the corner
+    /// `corner_x`, `corner_y`, which should be axis-aligned with both ends. Degenerates to nothing
+    /// if the current point is already at `x`, `y`.
+    pub fn corner_to(&mut self, corner_x: f64, corner_y: f64, x: f64, y: f64) -> &mut Self {
+        let Some(start) = self.current_point() else {
+            return self.line_to(x, y);
+        };
+        if start == PointF::new(x, y) {
+            return self;
+        }
+
+        self.cubic_to(
+            start.x + (corner_x - start.x) * KAPPA,
+            start.y + (corner_y - start.y) * KAPPA,
+            x + (corner_x - x) * KAPPA,
+            y + (corner_y - y) * KAPPA,
+            x,
+            y,
+        )
+    }
+
+    /// Adds a circular arc around `center`, starting at `start_angle` and sweeping through
+    /// `sweep_angle`. Angles are in degrees, measured clockwise from the top, so a positive sweep
+    /// runs clockwise on screen.
+    ///
+    /// The arc is joined to the current point by a straight line, or starts a new subpath if there
+    /// is none or the last subpath was closed.
+    pub fn arc(
+        &mut self,
+        center: PointF,
+        radius: f64,
+        start_angle: f64,
+        sweep_angle: f64,
+    ) -> &mut Self {
+        let point_at = |angle: f64| {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            PointF::new(center.x + radius * sin, center.y - radius * cos)
+        };
+
+        let start = point_at(start_angle);
+        match self.elements.last() {
+            None | Some(PathElement::Close) => {
+                self.move_to(start.x, start.y);
+            }
+            Some(_) if self.current_point() != Some(start) => {
+                self.line_to(start.x, start.y);
+            }
+            Some(_) => {}
+        }
+
+        // Each segment spans at most a quarter turn, which a single cubic curve approximates
+        // closely enough. Its control points lie along the tangents at either end.
+        let segments = (sweep_angle.abs() / 90.0).ceil().max(1.0) as usize;
+        let step = sweep_angle / segments as f64;
+        let handle = 4.0 / 3.0 * (step.to_radians() / 4.0).tan() * radius;
+        let tangent = |angle: f64| {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            (cos * handle, sin * handle)
+        };
+
+        for i in 0..segments {
+            let (from_angle, to_angle) = (
+                start_angle + step * i as f64,
+                start_angle + step * (i + 1) as f64,
+            );
+            let (from, to) = (point_at(from_angle), point_at(to_angle));
+            let ((dx0, dy0), (dx1, dy1)) = (tangent(from_angle), tangent(to_angle));
+
+            self.cubic_to(
+                from.x + dx0,
+                from.y + dy0,
+                to.x - dx1,
+                to.y - dy1,
+                to.x,
+                to.y,
+            );
+        }
+
+        self
+    }
+
+    /// Returns the point the next segment starts from: the end point of the last segment, or the
+    /// start of the current subpath if it was just closed.
+    fn current_point(&self) -> Option<PointF> {
+        let mut subpath_start = None;
+        let mut current = None;
+
+        for element in &self.elements {
+            match *element {
+                PathElement::MoveTo(p) => {
+                    subpath_start = Some(p);
+                    current = Some(p);
+                }
+                PathElement::LineTo(p)
+                | PathElement::QuadTo(_, p)
+                | PathElement::CubicTo(_, _, p) => {
+                    // Segments without a preceding move start their subpath where they begin.
+                    subpath_start = subpath_start.or(current).or(Some(p));
+                    current = Some(p);
+                }
+                PathElement::Close => current = subpath_start,
+            }
+        }
+
+        current
+    }
+
+    pub fn close(&mut self) -> &mut Self {
+        self.elements.push(PathElement::Close);
+        self
+    }
+
+    /// Appends every subpath of `other` to this path.
+    pub fn extend(&mut self, other: &Path) -> &mut Self {
+        self.elements.extend_from_slice(&other.elements);
+        self
+    }
+
+    /// Returns a copy of the path with every point, including control points, passed through `f`.
+    pub fn map_points(&self, f: impl Fn(PointF) -> PointF) -> Path {
+        let elements = self
+            .elements
+            .iter()
+            .map(|element| match *element {
+                PathElement::MoveTo(p) => PathElement::MoveTo(f(p)),
+                PathElement::LineTo(p) => PathElement::LineTo(f(p)),
+                PathElement::QuadTo(c, p) => PathElement::QuadTo(f(c), f(p)),
+                PathElement::CubicTo(c1, c2, p) => PathElement::CubicTo(f(c1), f(c2), f(p)),
+                PathElement::Close => PathElement::Close,
+            })
+            .collect();
+
+        Path {
+            elements,
+            fill_rule: self.fill_rule,
+        }
+    }
+
+    pub fn set_fill_rule(&mut self, fill_rule: FillRule) -> &mut Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    pub fn fill_rule(&self) -> FillRule {
+        self.fill_rule
+    }
+
+    pub fn elements(&self) -> &[PathElement] {
+        &self.elements
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the smallest rectangle containing every point of the path, including Bézier
+    /// control points, or [`None`] if the path is empty.
+    pub fn bounds(&self) -> Option<RectF> {
+        let mut points = self.elements.iter().flat_map(|element| match *element {
+            PathElement::MoveTo(p) | PathElement::LineTo(p) => vec![p],
+            PathElement::QuadTo(c, p) => vec![c, p],
+            PathElement::CubicTo(c1, c2, p) => vec![c1, c2, p],
+            PathElement::Close => vec![],
+        });
+
+        let first = points.next()?;
+        let (mut min, mut max) = (first, first);
+        for PointF { x, y } in points {
+            min.x = min.x.min(x);
+            min.y = min.y.min(y);
+            max.x = max.x.max(x);
+            max.y = max.y.max(y);
+        }
+
+        Some(RectF::new(min.x, min.y, max.x - min.x, max.y - min.y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rect_path_bounds() {
+        let rect = RectF::new(2.0, 4.0, 10.0, 6.0);
+
+        assert_eq!(Path::rect(rect).bounds(), Some(rect));
+        assert_eq!(Path::new().bounds(), None);
+    }
+
+    #[test]
+    fn rounded_rect_radii_are_scaled_to_fit() {
+        let rect = RectF::new(0.0, 0.0, 20.0, 10.0);
+        let path = Path::rounded_rect(rect, [10.0, 10.0, 10.0, 10.0]);
+
+        assert_eq!(path.bounds(), Some(rect));
+        // Radii are halved to 5.0, so the top edge runs between x = 5.0 and x = 15.0.
+        assert_eq!(
+            path.elements()[..2],
+            [
+                PathElement::MoveTo(PointF::new(5.0, 0.0)),
+                PathElement::LineTo(PointF::new(15.0, 0.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn rounded_rect_skips_square_corners() {
+        let rect = RectF::new(0.0, 0.0, 20.0, 10.0);
+        let curves = Path::rounded_rect(rect, [4.0, 0.0, 0.0, 4.0])
+            .elements()
+            .iter()
+            .filter(|element| matches!(element, PathElement::CubicTo(..)))
+            .count();
+
+        assert_eq!(curves, 2);
+    }
+
+    fn assert_close(actual: PointF, expected: PointF) {
+        assert!(
+            (actual.x - expected.x).abs() < 1e-9 && (actual.y - expected.y).abs() < 1e-9,
+            "{actual:?} is not {expected:?}"
+        );
+    }
+
+    #[test]
+    fn arc_runs_clockwise_from_top() {
+        let mut path = Path::new();
+        path.arc(PointF::new(10.0, 10.0), 5.0, 0.0, 180.0);
+
+        let elements = path.elements();
+        assert_eq!(elements.len(), 3);
+        let PathElement::MoveTo(start) = elements[0] else {
+            panic!("arc does not start a subpath: {elements:?}");
+        };
+        let PathElement::CubicTo(_, _, middle) = elements[1] else {
+            panic!("arc is not made of curves: {elements:?}");
+        };
+        let PathElement::CubicTo(_, _, end) = elements[2] else {
+            panic!("arc is not made of curves: {elements:?}");
+        };
+        assert_close(start, PointF::new(10.0, 5.0));
+        assert_close(middle, PointF::new(15.0, 10.0));
+        assert_close(end, PointF::new(10.0, 15.0));
+
+        // A following arc is joined with a line, unless it starts where the last one ended.
+        path.arc(PointF::new(10.0, 10.0), 5.0, 180.0, -90.0).arc(
+            PointF::new(10.0, 10.0),
+            2.0,
+            90.0,
+            90.0,
+        );
+        assert!(matches!(path.elements()[3], PathElement::CubicTo(..)));
+        assert!(matches!(path.elements()[4], PathElement::LineTo(..)));
+    }
+
+    #[test]
+    fn current_point_returns_to_start_after_close() {
+        let mut path = Path::new();
+        path.move_to(0.0, 0.0)
+            .line_to(10.0, 0.0)
+            .line_to(10.0, 10.0);
+        assert_eq!(path.current_point(), Some(PointF::new(10.0, 10.0)));
+
+        path.close();
+        assert_eq!(path.current_point(), Some(PointF::new(0.0, 0.0)));
+
+        // A corner from the start point back to itself adds nothing.
+        path.corner_to(0.0, 10.0, 0.0, 0.0);
+        assert_eq!(path.elements().last(), Some(&PathElement::Close));
+
+        path.move_to(20.0, 20.0).line_to(30.0, 20.0).close();
+        assert_eq!(path.current_point(), Some(PointF::new(20.0, 20.0)));
+    }
+
+    #[test]
+    fn circle_bounds() {
+        let path = Path::circle(PointF::new(4.0, 6.0), 3.0);
+
+        assert_eq!(path.bounds(), Some(RectF::new(1.0, 3.0, 6.0, 6.0)));
+        assert_eq!(path.elements().last(), Some(&PathElement::Close));
+    }
+
+    #[test]
+    fn bounds_include_control_points() {
+        let mut path = Path::new();
+        path.move_to(0.0, 0.0).quad_to(5.0, -5.0, 10.0, 0.0);
+
+        assert_eq!(path.bounds(), Some(RectF::new(0.0, -5.0, 10.0, 5.0)));
+    }
+}
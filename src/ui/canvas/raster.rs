@@ -0,0 +1,521 @@
+use std::path::Path as FsPath;
+
+use tiny_skia::{
+    FillRule as SkFillRule, FilterQuality, GradientStop as SkGradientStop, LineCap as SkLineCap,
+    LineJoin as SkLineJoin, LinearGradient, Mask, Paint as SkPaint, PathBuilder, Pattern, Pixmap,
+    PixmapPaint, PixmapRef, Point as SkPoint, RadialGradient, Rect, Shader, SpreadMode, Stroke,
+    StrokeDash, Transform,
+};
+
+use crate::ui::{
+    color::Color,
+    geometry::{Bounds, RectF},
+    paint::{self, Gradient, GradientKind, Paint},
+    Drawable, Error, UiResult,
+};
+
+use super::{
+    blur, gradient, Bitmap, Canvas, FillRule, LineCap, LineJoin, Path, PathElement, StrokeStyle,
+};
+
+#[derive(Clone)]
+struct State {
+    fill_paint: Paint,
+    fill_area: RectF,
+    stroke_color: Color,
+    stroke_style: StrokeStyle,
+    clip: Option<Mask>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            fill_paint: Paint::Solid(Color::BLACK),
+            fill_area: RectF::default(),
+            stroke_color: Color::BLACK,
+            stroke_style: StrokeStyle::default(),
+            clip: None,
+        }
+    }
+}
+
+/// A [`Canvas`] that rasterizes on the CPU into an in-memory RGBA buffer, which can be written
+/// out as a PNG.
+///
+/// This backend does not depend on any platform graphics stack, so it can be used to preview
+/// drawables or render them in tests on any platform.
+pub struct RasterCanvas {
+    pixmap: Pixmap,
+    transform: Transform,
+    state: State,
+    stack: Vec<State>,
+    /// Pixmaps covered by the open transparency layers, along with the opacity each layer is
+    /// composited onto them with. The innermost layer is drawn into `pixmap`.
+    layers: Vec<(Pixmap, f64)>,
+}
+
+impl RasterCanvas {
+    /// Creates a transparent canvas of `width` by `height` pixels.
+    pub fn new(width: u32, height: u32) -> UiResult<Self> {
+        Self::with_scale(width, height, 1.0)
+    }
+
+    /// Creates a transparent canvas of `width` by `height` points, where each point is made up of
+    /// `scale` pixels in each direction (e.g. `2.0` for a Retina display).
+    pub fn with_scale(width: u32, height: u32, scale: f64) -> UiResult<Self> {
+        let pixel_width = (width as f64 * scale).ceil() as u32;
+        let pixel_height = (height as f64 * scale).ceil() as u32;
+        let pixmap = Pixmap::new(pixel_width, pixel_height)
+            .ok_or(Error::InvalidSurfaceSize(pixel_width, pixel_height))?;
+
+        Ok(Self {
+            pixmap,
+            transform: Transform::from_scale(scale as f32, scale as f32),
+            state: State::default(),
+            stack: Vec::new(),
+            layers: Vec::new(),
+        })
+    }
+
+    /// Renders `drawable` into a new canvas just large enough to hold `bounds`.
+    pub fn render(drawable: &dyn Drawable, bounds: Bounds) -> UiResult<Self> {
+        Self::render_with_scale(drawable, bounds, 1.0)
+    }
+
+    /// Renders `drawable` into a new canvas just large enough to hold `bounds`, with `scale`
+    /// pixels per point.
+    pub fn render_with_scale(
+        drawable: &dyn Drawable,
+        bounds: Bounds,
+        scale: f64,
+    ) -> UiResult<Self> {
+        let width = (bounds.position.x + bounds.size.width) as u32;
+        let height = (bounds.position.y + bounds.size.height) as u32;
+
+        let mut canvas = Self::with_scale(width, height, scale)?;
+        drawable.draw(&mut canvas, bounds)?;
+
+        Ok(canvas)
+    }
+
+    /// Width of the underlying buffer in pixels.
+    pub fn width(&self) -> u32 {
+        self.pixmap.width()
+    }
+
+    /// Height of the underlying buffer in pixels.
+    pub fn height(&self) -> u32 {
+        self.pixmap.height()
+    }
+
+    /// Copies the rendered pixels out of the canvas.
+    pub fn to_bitmap(&self) -> Bitmap {
+        Bitmap::from_premultiplied_rgba(self.width(), self.height(), self.pixmap.data().to_vec())
+            .expect("pixmap data always matches its dimensions")
+    }
+
+    /// Encodes the rendered pixels as a PNG image.
+    pub fn encode_png(&self) -> UiResult<Vec<u8>> {
+        self.pixmap
+            .encode_png()
+            .map_err(|err| Error::ImageEncoding(err.to_string()))
+    }
+
+    /// Encodes the rendered pixels as a PNG image and writes it to `path`.
+    pub fn save_png(&self, path: impl AsRef<FsPath>) -> UiResult<()> {
+        self.pixmap
+            .save_png(path)
+            .map_err(|err| Error::ImageEncoding(err.to_string()))
+    }
+
+    fn color(color: Color) -> tiny_skia::Color {
+        let [r, g, b, a] = color
+            .components()
+            .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+
+        tiny_skia::Color::from_rgba8(r, g, b, a)
+    }
+
+    fn paint(color: Color) -> SkPaint<'static> {
+        let mut paint = SkPaint::default();
+        paint.set_color(Self::color(color));
+
+        paint
+    }
+
+    /// Builds a shader for the gradients tiny-skia supports natively, which excludes conic ones.
+    fn gradient_shader(gradient: &Gradient, area: RectF) -> Option<Shader<'static>> {
+        let stops = gradient
+            .stops
+            .iter()
+            .map(|stop| SkGradientStop::new(stop.offset as f32, Self::color(stop.color)))
+            .collect();
+
+        match gradient.kind {
+            GradientKind::Linear { angle } => {
+                let (start, end) = paint::linear_endpoints(angle, area);
+                LinearGradient::new(
+                    SkPoint::from_xy(start.x as f32, start.y as f32),
+                    SkPoint::from_xy(end.x as f32, end.y as f32),
+                    stops,
+                    SpreadMode::Pad,
+                    Transform::identity(),
+                )
+            }
+            GradientKind::Radial { center, radius } => {
+                let (center, radius) = paint::radial_circle(center, radius, area);
+                let center = SkPoint::from_xy(center.x as f32, center.y as f32);
+                RadialGradient::new(
+                    center,
+                    center,
+                    radius as f32,
+                    stops,
+                    SpreadMode::Pad,
+                    Transform::identity(),
+                )
+            }
+            GradientKind::Conic { .. } => None,
+        }
+    }
+
+    /// Calls `fill` with the pixmap to draw into, along with the current fill paint, transform and
+    /// clip.
+    fn with_fill(&mut self, fill: impl FnOnce(&mut Pixmap, &SkPaint, Transform, Option<&Mask>)) {
+        let clip = self.state.clip.as_ref();
+        let gradient = match &self.state.fill_paint {
+            Paint::Solid(color) => {
+                return fill(&mut self.pixmap, &Self::paint(*color), self.transform, clip);
+            }
+            Paint::Gradient(gradient) => gradient,
+        };
+        let area = self.state.fill_area;
+
+        if let Some(shader) = Self::gradient_shader(gradient, area) {
+            let paint = SkPaint {
+                shader,
+                ..Default::default()
+            };
+            return fill(&mut self.pixmap, &paint, self.transform, clip);
+        }
+
+        // Fall back to sampling the gradient on the CPU, at the resolution of the canvas.
+        let bitmap = gradient::rasterize(gradient, area, self.transform.sx as f64);
+        let Some(pixmap) = bitmap.as_ref().and_then(|bitmap| {
+            PixmapRef::from_bytes(bitmap.data(), bitmap.width(), bitmap.height())
+        }) else {
+            // Degenerate gradients paint as their first color, like tiny-skia's own.
+            let paint = Self::paint(gradient.color_at(0.0));
+            return fill(&mut self.pixmap, &paint, self.transform, clip);
+        };
+
+        let pattern_transform = Transform::from_row(
+            (area.width / pixmap.width() as f64) as f32,
+            0.0,
+            0.0,
+            (area.height / pixmap.height() as f64) as f32,
+            area.x as f32,
+            area.y as f32,
+        );
+        let paint = SkPaint {
+            shader: Pattern::new(
+                pixmap,
+                SpreadMode::Pad,
+                FilterQuality::Bilinear,
+                1.0,
+                pattern_transform,
+            ),
+            ..Default::default()
+        };
+        fill(&mut self.pixmap, &paint, self.transform, clip);
+    }
+
+    pub(super) fn build_path(path: &Path) -> Option<tiny_skia::Path> {
+        let mut builder = PathBuilder::new();
+        for element in path.elements() {
+            match *element {
+                PathElement::MoveTo(p) => builder.move_to(p.x as f32, p.y as f32),
+                PathElement::LineTo(p) => builder.line_to(p.x as f32, p.y as f32),
+                PathElement::QuadTo(c, p) => {
+                    builder.quad_to(c.x as f32, c.y as f32, p.x as f32, p.y as f32)
+                }
+                PathElement::CubicTo(c1, c2, p) => builder.cubic_to(
+                    c1.x as f32,
+                    c1.y as f32,
+                    c2.x as f32,
+                    c2.y as f32,
+                    p.x as f32,
+                    p.y as f32,
+                ),
+                PathElement::Close => builder.close(),
+            }
+        }
+
+        builder.finish()
+    }
+
+    fn fill_rule(path: &Path) -> SkFillRule {
+        match path.fill_rule() {
+            FillRule::NonZero => SkFillRule::Winding,
+            FillRule::EvenOdd => SkFillRule::EvenOdd,
+        }
+    }
+
+    fn build_rect(rect: RectF) -> Option<Rect> {
+        Rect::from_xywh(
+            rect.x as f32,
+            rect.y as f32,
+            rect.width as f32,
+            rect.height as f32,
+        )
+    }
+
+    fn clip_to(&mut self, path: &tiny_skia::Path, fill_rule: SkFillRule) {
+        if let Some(clip) = &mut self.state.clip {
+            clip.intersect_path(path, fill_rule, true, self.transform);
+        } else {
+            let mut clip = Mask::new(self.pixmap.width(), self.pixmap.height())
+                .expect("mask has the same non-zero size as the pixmap");
+            clip.fill_path(path, fill_rule, true, self.transform);
+            self.state.clip = Some(clip);
+        }
+    }
+}
+
+impl Canvas for RasterCanvas {
+    fn save(&mut self) {
+        self.stack.push(self.state.clone());
+    }
+
+    fn restore(&mut self) {
+        if let Some(state) = self.stack.pop() {
+            self.state = state;
+        }
+    }
+
+    fn set_fill_color(&mut self, color: Color) {
+        self.state.fill_paint = Paint::Solid(color);
+    }
+
+    fn set_fill_paint(&mut self, paint: &Paint, area: RectF) {
+        self.state.fill_paint = paint.clone();
+        self.state.fill_area = area;
+    }
+
+    fn set_stroke_color(&mut self, color: Color) {
+        self.state.stroke_color = color;
+    }
+
+    fn set_stroke_style(&mut self, style: &StrokeStyle) {
+        self.state.stroke_style = style.clone();
+    }
+
+    fn fill_rect(&mut self, rect: RectF) {
+        // `Pixmap::fill_rect` trips over anti-aliased rectangles less than a pixel wide, which
+        // filling the equivalent path handles fine.
+        if Self::build_rect(rect).is_some() {
+            self.fill_path(&Path::rect(rect));
+        }
+    }
+
+    fn stroke_rect(&mut self, rect: RectF) {
+        self.stroke_path(&Path::rect(rect));
+    }
+
+    fn fill_path(&mut self, path: &Path) {
+        if let Some(sk_path) = Self::build_path(path) {
+            let fill_rule = Self::fill_rule(path);
+            self.with_fill(|pixmap, paint, transform, clip| {
+                pixmap.fill_path(&sk_path, paint, fill_rule, transform, clip)
+            });
+        }
+    }
+
+    fn stroke_path(&mut self, path: &Path) {
+        if let Some(path) = Self::build_path(path) {
+            let style = &self.state.stroke_style;
+            let stroke = Stroke {
+                width: style.width as f32,
+                dash: style.dash.as_ref().and_then(|dash| {
+                    let mut lengths: Vec<f32> =
+                        dash.lengths.iter().map(|length| *length as f32).collect();
+                    if lengths.len() % 2 == 1 {
+                        lengths.extend_from_within(..);
+                    }
+
+                    StrokeDash::new(lengths, dash.offset as f32)
+                }),
+                line_cap: match style.cap {
+                    LineCap::Butt => SkLineCap::Butt,
+                    LineCap::Round => SkLineCap::Round,
+                    LineCap::Square => SkLineCap::Square,
+                },
+                line_join: match style.join {
+                    LineJoin::Miter => SkLineJoin::Miter,
+                    LineJoin::Round => SkLineJoin::Round,
+                    LineJoin::Bevel => SkLineJoin::Bevel,
+                },
+                miter_limit: style.miter_limit as f32,
+            };
+
+            self.pixmap.stroke_path(
+                &path,
+                &Self::paint(self.state.stroke_color),
+                &stroke,
+                self.transform,
+                self.state.clip.as_ref(),
+            );
+        }
+    }
+
+    fn clip_rect(&mut self, rect: RectF) {
+        self.clip_path(&Path::rect(rect));
+    }
+
+    fn clip_path(&mut self, path: &Path) {
+        match Self::build_path(path) {
+            Some(sk_path) => self.clip_to(&sk_path, Self::fill_rule(path)),
+            // An empty path encloses nothing, so everything is clipped away.
+            None => {
+                let mask = Mask::new(self.pixmap.width(), self.pixmap.height())
+                    .expect("mask has the same non-zero size as the pixmap");
+                self.state.clip = Some(mask);
+            }
+        }
+    }
+
+    fn draw_image(&mut self, image: &Bitmap, rect: RectF) {
+        let (Some(pixmap), Some(dest)) = (
+            PixmapRef::from_bytes(image.data(), image.width(), image.height()),
+            Self::build_rect(rect),
+        ) else {
+            return;
+        };
+
+        let image_transform = Transform::from_row(
+            (rect.width / image.width() as f64) as f32,
+            0.0,
+            0.0,
+            (rect.height / image.height() as f64) as f32,
+            rect.x as f32,
+            rect.y as f32,
+        );
+        let paint = SkPaint {
+            shader: Pattern::new(
+                pixmap,
+                SpreadMode::Pad,
+                FilterQuality::Bilinear,
+                1.0,
+                image_transform,
+            ),
+            ..Default::default()
+        };
+
+        self.pixmap
+            .fill_rect(dest, &paint, self.transform, self.state.clip.as_ref());
+    }
+
+    fn fill_shadow(&mut self, path: &Path, color: Color, blur_radius: f64) {
+        if let Some((bitmap, rect)) = blur::blurred_path(path, color, blur_radius, self.scale()) {
+            self.draw_image(&bitmap, rect);
+        }
+    }
+
+    fn begin_layer(&mut self, alpha: f64) {
+        self.save();
+        let layer = Pixmap::new(self.pixmap.width(), self.pixmap.height())
+            .expect("layer has the same non-zero size as the pixmap");
+        let below = std::mem::replace(&mut self.pixmap, layer);
+        self.layers.push((below, alpha));
+    }
+
+    fn end_layer(&mut self) {
+        let Some((below, alpha)) = self.layers.pop() else {
+            return;
+        };
+
+        // The layer was clipped as it was drawn, so it is composited as a whole.
+        let layer = std::mem::replace(&mut self.pixmap, below);
+        self.pixmap.draw_pixmap(
+            0,
+            0,
+            layer.as_ref(),
+            &PixmapPaint {
+                opacity: alpha.clamp(0.0, 1.0) as f32,
+                ..Default::default()
+            },
+            Transform::identity(),
+            None,
+        );
+        self.restore();
+    }
+
+    fn scale(&self) -> f64 {
+        self.transform.sx as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::block::{Block, Props};
+
+    use super::*;
+
+    #[test]
+    fn renders_block_background() {
+        let block = Block::new(
+            (),
+            Props {
+                background_color: Some(Color::RED.into()),
+                width: Some(4),
+                height: Some(2),
+                ..Default::default()
+            },
+        );
+
+        let bitmap = RasterCanvas::render(&block, Bounds::new(0, 0, 6, 2))
+            .unwrap()
+            .to_bitmap();
+
+        assert_eq!(bitmap.pixel(0, 0), Some([255, 0, 0, 255]));
+        assert_eq!(bitmap.pixel(3, 1), Some([255, 0, 0, 255]));
+        assert_eq!(bitmap.pixel(4, 0), Some([0, 0, 0, 0]));
+        assert_eq!(bitmap.pixel(6, 0), None);
+    }
+
+    #[test]
+    fn clip_limits_fills() {
+        let mut canvas = RasterCanvas::new(4, 4).unwrap();
+        canvas.save();
+        canvas.clip_rect(RectF::new(0.0, 0.0, 2.0, 4.0));
+        canvas.set_fill_color(Color::BLUE);
+        canvas.fill_rect(RectF::new(0.0, 0.0, 4.0, 4.0));
+        canvas.restore();
+
+        let bitmap = canvas.to_bitmap();
+        assert_eq!(bitmap.pixel(1, 1), Some([0, 0, 255, 255]));
+        assert_eq!(bitmap.pixel(2, 1), Some([0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn scale_multiplies_pixel_size() {
+        let canvas = RasterCanvas::with_scale(10, 4, 2.0).unwrap();
+
+        assert_eq!((canvas.width(), canvas.height()), (20, 8));
+    }
+
+    #[test]
+    fn layers_composite_as_a_group() {
+        let mut canvas = RasterCanvas::new(3, 1).unwrap();
+        canvas.begin_layer(0.5);
+        canvas.set_fill_color(Color::BLUE);
+        canvas.fill_rect(RectF::new(0.0, 0.0, 2.0, 1.0));
+        canvas.fill_rect(RectF::new(1.0, 0.0, 2.0, 1.0));
+        canvas.end_layer();
+
+        // The overlapping pixel is no more opaque than the others.
+        let bitmap = canvas.to_bitmap();
+        assert_eq!(bitmap.pixel(0, 0), bitmap.pixel(1, 0));
+        assert_eq!(bitmap.pixel(1, 0), bitmap.pixel(2, 0));
+        assert_eq!(bitmap.pixel(1, 0).map(|pixel| pixel[3]), Some(128));
+    }
+}
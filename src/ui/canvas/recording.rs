@@ -0,0 +1,354 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ui::{
+    color::Color,
+    font::{Font, FontDescriptor},
+    geometry::{Bounds, PointF, RectF},
+    paint::Paint,
+    Drawable, UiResult,
+};
+
+use super::{Bitmap, Canvas, Path, StrokeStyle};
+
+/// A single operation captured by a [`RecordingCanvas`], mirroring the [`Canvas`] method that
+/// produced it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DisplayItem {
+    Save,
+    Restore,
+    SetFillColor(Color),
+    SetFillPaint {
+        paint: Paint,
+        area: RectF,
+    },
+    SetStrokeColor(Color),
+    SetStrokeStyle(StrokeStyle),
+    FillRect(RectF),
+    StrokeRect(RectF),
+    FillPath(Path),
+    StrokePath(Path),
+    ClipRect(RectF),
+    ClipPath(Path),
+    /// Only the dimensions of the image are recorded, not its pixels.
+    DrawImage {
+        width: u32,
+        height: u32,
+        rect: RectF,
+    },
+    FillShadow {
+        path: Path,
+        color: Color,
+        blur_radius: f64,
+    },
+    /// Only the description of the font is recorded, not the outlines of the glyphs.
+    FillText {
+        text: String,
+        origin: PointF,
+        font: FontDescriptor,
+        letter_spacing: f64,
+    },
+    BeginLayer {
+        alpha: f64,
+    },
+    EndLayer,
+}
+
+/// A difference between two [`DisplayList`]s, as produced by [`DisplayList::diff`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DisplayListChange {
+    /// `item` at `index` in the old list is not present in the new list.
+    Removed { index: usize, item: DisplayItem },
+    /// `item` at `index` in the new list is not present in the old list.
+    Added { index: usize, item: DisplayItem },
+}
+
+/// An ordered list of drawing operations.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct DisplayList {
+    items: Vec<DisplayItem>,
+}
+
+impl DisplayList {
+    pub fn items(&self) -> &[DisplayItem] {
+        &self.items
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Computes the smallest set of removals and additions that turns `self` into `other`, e.g.
+    /// to find out what changed between two frames.
+    ///
+    /// Removals are listed before additions, each in ascending index order.
+    pub fn diff(&self, other: &DisplayList) -> Vec<DisplayListChange> {
+        let (old, new) = (&self.items, &other.items);
+
+        let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+        let suffix = old[prefix..]
+            .iter()
+            .rev()
+            .zip(new[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let old_middle = &old[prefix..old.len() - suffix];
+        let new_middle = &new[prefix..new.len() - suffix];
+
+        // Longest common subsequence table, where `lcs[i][j]` is the length of the LCS of
+        // `old_middle[i..]` and `new_middle[j..]`.
+        let mut lcs = vec![vec![0usize; new_middle.len() + 1]; old_middle.len() + 1];
+        for i in (0..old_middle.len()).rev() {
+            for j in (0..new_middle.len()).rev() {
+                lcs[i][j] = if old_middle[i] == new_middle[j] {
+                    lcs[i + 1][j + 1] + 1
+                } else {
+                    lcs[i + 1][j].max(lcs[i][j + 1])
+                };
+            }
+        }
+
+        let mut removed = Vec::new();
+        let mut added = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < old_middle.len() || j < new_middle.len() {
+            if i < old_middle.len() && j < new_middle.len() && old_middle[i] == new_middle[j] {
+                i += 1;
+                j += 1;
+            } else if j == new_middle.len()
+                || (i < old_middle.len() && lcs[i + 1][j] >= lcs[i][j + 1])
+            {
+                removed.push(DisplayListChange::Removed {
+                    index: prefix + i,
+                    item: old_middle[i].clone(),
+                });
+                i += 1;
+            } else {
+                added.push(DisplayListChange::Added {
+                    index: prefix + j,
+                    item: new_middle[j].clone(),
+                });
+                j += 1;
+            }
+        }
+
+        removed.extend(added);
+        removed
+    }
+}
+
+impl IntoIterator for DisplayList {
+    type Item = DisplayItem;
+    type IntoIter = std::vec::IntoIter<DisplayItem>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+/// A [`Canvas`] that records every operation into a [`DisplayList`] instead of drawing pixels.
+///
+/// This is useful for asserting exactly what a drawable emits without any graphics stack.
+#[derive(Debug, Default)]
+pub struct RecordingCanvas {
+    list: DisplayList,
+}
+
+impl RecordingCanvas {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draws `drawable` at `bounds` and returns the operations it emitted.
+    pub fn record(drawable: &dyn Drawable, bounds: Bounds) -> UiResult<DisplayList> {
+        let mut canvas = Self::new();
+        drawable.draw(&mut canvas, bounds)?;
+
+        Ok(canvas.finish())
+    }
+
+    /// Returns the operations recorded so far.
+    pub fn display_list(&self) -> &DisplayList {
+        &self.list
+    }
+
+    /// Consumes the canvas, returning every recorded operation.
+    pub fn finish(self) -> DisplayList {
+        self.list
+    }
+
+    fn push(&mut self, item: DisplayItem) {
+        self.list.items.push(item);
+    }
+}
+
+impl Canvas for RecordingCanvas {
+    fn save(&mut self) {
+        self.push(DisplayItem::Save);
+    }
+
+    fn restore(&mut self) {
+        self.push(DisplayItem::Restore);
+    }
+
+    fn set_fill_color(&mut self, color: Color) {
+        self.push(DisplayItem::SetFillColor(color));
+    }
+
+    fn set_fill_paint(&mut self, paint: &Paint, area: RectF) {
+        self.push(DisplayItem::SetFillPaint {
+            paint: paint.clone(),
+            area,
+        });
+    }
+
+    fn set_stroke_color(&mut self, color: Color) {
+        self.push(DisplayItem::SetStrokeColor(color));
+    }
+
+    fn set_stroke_style(&mut self, style: &StrokeStyle) {
+        self.push(DisplayItem::SetStrokeStyle(style.clone()));
+    }
+
+    fn fill_rect(&mut self, rect: RectF) {
+        self.push(DisplayItem::FillRect(rect));
+    }
+
+    fn stroke_rect(&mut self, rect: RectF) {
+        self.push(DisplayItem::StrokeRect(rect));
+    }
+
+    fn fill_path(&mut self, path: &Path) {
+        self.push(DisplayItem::FillPath(path.clone()));
+    }
+
+    fn stroke_path(&mut self, path: &Path) {
+        self.push(DisplayItem::StrokePath(path.clone()));
+    }
+
+    fn clip_rect(&mut self, rect: RectF) {
+        self.push(DisplayItem::ClipRect(rect));
+    }
+
+    fn clip_path(&mut self, path: &Path) {
+        self.push(DisplayItem::ClipPath(path.clone()));
+    }
+
+    fn draw_image(&mut self, image: &Bitmap, rect: RectF) {
+        self.push(DisplayItem::DrawImage {
+            width: image.width(),
+            height: image.height(),
+            rect,
+        });
+    }
+
+    fn fill_shadow(&mut self, path: &Path, color: Color, blur_radius: f64) {
+        self.push(DisplayItem::FillShadow {
+            path: path.clone(),
+            color,
+            blur_radius,
+        });
+    }
+
+    fn begin_layer(&mut self, alpha: f64) {
+        self.push(DisplayItem::BeginLayer { alpha });
+    }
+
+    fn end_layer(&mut self) {
+        self.push(DisplayItem::EndLayer);
+    }
+
+    /// Recordings are made in points, so they do not have a resolution of their own.
+    fn scale(&self) -> f64 {
+        1.0
+    }
+
+    fn fill_text(&mut self, text: &str, origin: PointF, font: &Font, letter_spacing: f64) {
+        self.push(DisplayItem::FillText {
+            text: text.to_string(),
+            origin,
+            font: font.descriptor(),
+            letter_spacing,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        block::{self, Block},
+        layout::{Direction, Layout, Props},
+    };
+
+    use super::*;
+
+    fn square(color: Color) -> Block {
+        Block::new(
+            (),
+            block::Props {
+                background_color: Some(color.into()),
+                width: Some(10),
+                height: Some(10),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn records_layout_backgrounds() {
+        let layout = Layout::with_children(
+            vec![square(Color::RED), square(Color::BLUE)],
+            Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+
+        let list = RecordingCanvas::record(&layout, Bounds::new(0, 0, 100, 10)).unwrap();
+
+        assert_eq!(
+            list.items(),
+            [
+                DisplayItem::SetFillColor(Color::RED),
+                DisplayItem::FillRect(RectF::new(0.0, 0.0, 10.0, 10.0)),
+                DisplayItem::SetFillColor(Color::BLUE),
+                DisplayItem::FillRect(RectF::new(10.0, 0.0, 10.0, 10.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_reports_changed_items() {
+        let bounds = Bounds::new(0, 0, 100, 10);
+        let before = RecordingCanvas::record(&square(Color::RED), bounds).unwrap();
+        let after = RecordingCanvas::record(&square(Color::GREEN), bounds).unwrap();
+
+        assert!(before.diff(&before).is_empty());
+        assert_eq!(
+            before.diff(&after),
+            [
+                DisplayListChange::Removed {
+                    index: 0,
+                    item: DisplayItem::SetFillColor(Color::RED),
+                },
+                DisplayListChange::Added {
+                    index: 0,
+                    item: DisplayItem::SetFillColor(Color::GREEN),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn display_list_round_trips_through_json() {
+        let list = RecordingCanvas::record(&square(Color::RED), Bounds::new(0, 0, 10, 10)).unwrap();
+
+        let json = serde_json::to_string(&list).unwrap();
+        let parsed: DisplayList = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, list);
+    }
+}
@@ -1,7 +1,9 @@
+#[cfg(target_os = "macos")]
 use core_graphics::color::CGColor;
+use serde::{Deserialize, Serialize};
 
 /// Represents an RGBA color value.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Color(f64, f64, f64, f64);
 
 impl Color {
@@ -19,8 +21,23 @@ impl Color {
     pub const fn rgba(r: f64, g: f64, b: f64, a: f64) -> Self {
         Self(r, g, b, a)
     }
+
+    /// Returns the `[r, g, b, a]` components of the color.
+    pub const fn components(&self) -> [f64; 4] {
+        [self.0, self.1, self.2, self.3]
+    }
+
+    pub const fn alpha(&self) -> f64 {
+        self.3
+    }
+
+    /// Returns the same color with its alpha channel replaced by `alpha`.
+    pub const fn with_alpha(self, alpha: f64) -> Self {
+        Self(self.0, self.1, self.2, alpha)
+    }
 }
 
+#[cfg(target_os = "macos")]
 impl Into<CGColor> for Color {
     fn into(self) -> CGColor {
         let Color(r, g, b, a) = self;
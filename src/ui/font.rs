@@ -0,0 +1,537 @@
+//! Font loading, selection and measurement.
+//!
+//! Drawables never talk to a font file directly. Instead they go through the [`FontFace`] trait,
+//! which exposes just the metrics and outlines needed to lay out and draw text, so that layout can
+//! be exercised headlessly with the fonts bundled in `assets/fonts`.
+
+use std::{
+    borrow::Cow,
+    fmt,
+    path::Path as FsPath,
+    sync::{Arc, OnceLock},
+};
+
+use serde::{Deserialize, Serialize};
+use ttf_parser::{name_id, OutlineBuilder};
+
+use super::{
+    canvas::Path,
+    geometry::{PointF, RectF},
+    Error, UiResult,
+};
+
+/// Family of the fonts bundled with wunderbar, which is always available.
+pub const DEFAULT_FAMILY: &str = "DejaVu Sans";
+
+/// Size in points of [`Font::default`].
+pub const DEFAULT_SIZE: f64 = 13.0;
+
+const BUNDLED_REGULAR: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans.ttf");
+const BUNDLED_BOLD: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans-Bold.ttf");
+
+/// Index of a glyph within a [`FontFace`].
+pub type GlyphId = u16;
+
+/// Thickness of the strokes of a font, on the usual scale from 100 (thin) to 900 (black).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct FontWeight(pub u16);
+
+impl FontWeight {
+    pub const THIN: Self = Self(100);
+    pub const LIGHT: Self = Self(300);
+    pub const REGULAR: Self = Self(400);
+    pub const MEDIUM: Self = Self(500);
+    pub const SEMIBOLD: Self = Self(600);
+    pub const BOLD: Self = Self(700);
+    pub const BLACK: Self = Self(900);
+}
+
+impl Default for FontWeight {
+    fn default() -> Self {
+        Self::REGULAR
+    }
+}
+
+/// A single typeface of a font family, such as "DejaVu Sans Bold".
+///
+/// All metrics are expressed in font units, of which there are [`FontFace::units_per_em`] per
+/// em, with the y axis growing upwards from the baseline.
+pub trait FontFace: fmt::Debug + Send + Sync {
+    fn family(&self) -> &str;
+
+    fn weight(&self) -> FontWeight;
+
+    fn units_per_em(&self) -> f64;
+
+    /// Distance from the baseline to the top of the tallest glyphs.
+    fn ascender(&self) -> f64;
+
+    /// Distance from the baseline to the bottom of the lowest glyphs, as a positive number.
+    fn descender(&self) -> f64;
+
+    /// Recommended extra space between consecutive lines.
+    fn line_gap(&self) -> f64;
+
+    /// Returns the glyph for `c`, or [`None`] if the face does not cover it.
+    fn glyph_id(&self, c: char) -> Option<GlyphId>;
+
+    /// Horizontal distance from the start of `glyph` to the start of the next one.
+    fn advance(&self, glyph: GlyphId) -> f64;
+
+    /// Adjustment to the advance between `left` and `right` when they appear next to each other.
+    fn kerning(&self, _left: GlyphId, _right: GlyphId) -> f64 {
+        0.0
+    }
+
+    /// Returns the outline of `glyph`, or [`None`] if it has no outline (such as a space).
+    fn outline(&self, glyph: GlyphId) -> Option<Path>;
+}
+
+/// A [`FontFace`] read from a TrueType or OpenType font file.
+///
+/// The font file is parsed once when the face is loaded, and font files that are not already
+/// `'static` are owned by the face for as long as it lives.
+pub struct TtfFace {
+    // Borrows from `_data`, so it is declared first to be dropped before it.
+    face: ttf_parser::Face<'static>,
+    _data: Option<Arc<[u8]>>,
+    family: String,
+    weight: FontWeight,
+    units_per_em: f64,
+    ascender: f64,
+    descender: f64,
+    line_gap: f64,
+}
+
+impl TtfFace {
+    /// Parses the face at `index` in the font file `data`. Font collections (`.ttc` files) can
+    /// contain several faces, whereas plain font files only have a face at index 0.
+    pub fn from_bytes(data: impl Into<Cow<'static, [u8]>>, index: u32) -> UiResult<Self> {
+        let (data, bytes): (Option<Arc<[u8]>>, &'static [u8]) = match data.into() {
+            Cow::Borrowed(bytes) => (None, bytes),
+            Cow::Owned(bytes) => {
+                let data: Arc<[u8]> = bytes.into();
+                // SAFETY: the bytes stay on the heap, unmoved and unchanged, for as long as the
+                // face holds on to `data`, and the borrow never outlives `face`, which is private
+                // and dropped first.
+                let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr(), data.len()) };
+                (Some(data), bytes)
+            }
+        };
+        let face = ttf_parser::Face::parse(bytes, index)
+            .map_err(|err| Error::InvalidFont(err.to_string()))?;
+
+        let name = |id| {
+            face.names()
+                .into_iter()
+                .filter(|name| name.name_id == id && name.is_unicode())
+                .find_map(|name| name.to_string())
+        };
+        let family = name(name_id::TYPOGRAPHIC_FAMILY)
+            .or_else(|| name(name_id::FAMILY))
+            .ok_or_else(|| Error::InvalidFont("font has no family name".to_string()))?;
+
+        Ok(Self {
+            family,
+            weight: FontWeight(face.weight().to_number()),
+            units_per_em: face.units_per_em() as f64,
+            ascender: face.ascender() as f64,
+            descender: -(face.descender() as f64),
+            line_gap: face.line_gap() as f64,
+            face,
+            _data: data,
+        })
+    }
+
+    /// Reads and parses the first face of the font file at `path`.
+    pub fn load(path: impl AsRef<FsPath>) -> UiResult<Self> {
+        let data = std::fs::read(path).map_err(|err| Error::InvalidFont(err.to_string()))?;
+
+        Self::from_bytes(data, 0)
+    }
+}
+
+impl fmt::Debug for TtfFace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TtfFace")
+            .field("family", &self.family)
+            .field("weight", &self.weight)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FontFace for TtfFace {
+    fn family(&self) -> &str {
+        &self.family
+    }
+
+    fn weight(&self) -> FontWeight {
+        self.weight
+    }
+
+    fn units_per_em(&self) -> f64 {
+        self.units_per_em
+    }
+
+    fn ascender(&self) -> f64 {
+        self.ascender
+    }
+
+    fn descender(&self) -> f64 {
+        self.descender
+    }
+
+    fn line_gap(&self) -> f64 {
+        self.line_gap
+    }
+
+    fn glyph_id(&self, c: char) -> Option<GlyphId> {
+        self.face.glyph_index(c).map(|glyph| glyph.0)
+    }
+
+    fn advance(&self, glyph: GlyphId) -> f64 {
+        self.face
+            .glyph_hor_advance(ttf_parser::GlyphId(glyph))
+            .unwrap_or(0) as f64
+    }
+
+    fn kerning(&self, left: GlyphId, right: GlyphId) -> f64 {
+        let Some(kern) = self.face.tables().kern else {
+            return 0.0;
+        };
+
+        kern.subtables
+            .into_iter()
+            .filter(|subtable| subtable.horizontal && !subtable.variable)
+            .find_map(|subtable| {
+                subtable.glyphs_kerning(ttf_parser::GlyphId(left), ttf_parser::GlyphId(right))
+            })
+            .unwrap_or(0) as f64
+    }
+
+    fn outline(&self, glyph: GlyphId) -> Option<Path> {
+        let mut builder = PathOutlineBuilder(Path::new());
+        self.face
+            .outline_glyph(ttf_parser::GlyphId(glyph), &mut builder)?;
+
+        Some(builder.0)
+    }
+}
+
+struct PathOutlineBuilder(Path);
+
+impl OutlineBuilder for PathOutlineBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.0.move_to(x as f64, y as f64);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.0.line_to(x as f64, y as f64);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.0.quad_to(x1 as f64, y1 as f64, x as f64, y as f64);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.0.cubic_to(
+            x1 as f64, y1 as f64, x2 as f64, y2 as f64, x as f64, y as f64,
+        );
+    }
+
+    fn close(&mut self) {
+        self.0.close();
+    }
+}
+
+/// Describes a font to look up in a [`FontCollection`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FontDescriptor {
+    pub family: String,
+    pub weight: FontWeight,
+    /// Size of the font in points.
+    pub size: f64,
+}
+
+impl FontDescriptor {
+    pub fn new(family: impl Into<String>, size: f64) -> Self {
+        Self {
+            family: family.into(),
+            weight: FontWeight::REGULAR,
+            size,
+        }
+    }
+
+    pub fn weight(mut self, weight: FontWeight) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+/// Vertical metrics of a [`Font`], in points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontMetrics {
+    /// Distance from the baseline to the top of the line.
+    pub ascent: f64,
+    /// Distance from the baseline to the bottom of the line.
+    pub descent: f64,
+    pub line_gap: f64,
+}
+
+impl FontMetrics {
+    /// Height of a single line of text, excluding the line gap.
+    pub fn height(&self) -> f64 {
+        self.ascent + self.descent
+    }
+}
+
+/// A [`FontFace`] at a given size, ready to measure and draw text.
+#[derive(Clone)]
+pub struct Font {
+    face: Arc<dyn FontFace>,
+    size: f64,
+}
+
+impl Font {
+    pub fn new(face: Arc<dyn FontFace>, size: f64) -> Self {
+        Self { face, size }
+    }
+
+    pub fn face(&self) -> &dyn FontFace {
+        self.face.as_ref()
+    }
+
+    /// Size of the font in points.
+    pub fn size(&self) -> f64 {
+        self.size
+    }
+
+    /// Returns the same face at a different size.
+    pub fn with_size(&self, size: f64) -> Self {
+        Self::new(self.face.clone(), size)
+    }
+
+    pub fn descriptor(&self) -> FontDescriptor {
+        FontDescriptor::new(self.face.family(), self.size).weight(self.face.weight())
+    }
+
+    pub fn metrics(&self) -> FontMetrics {
+        let scale = self.scale();
+
+        FontMetrics {
+            ascent: self.face.ascender() * scale,
+            descent: self.face.descender() * scale,
+            line_gap: self.face.line_gap() * scale,
+        }
+    }
+
+    /// Returns the width of `text` in points, with `letter_spacing` extra points between
+    /// consecutive characters.
+    pub fn measure(&self, text: &str, letter_spacing: f64) -> f64 {
+        self.layout(text, letter_spacing)
+            .last()
+            .map(|(glyph, x)| x + self.face.advance(glyph) * self.scale())
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the outlines of `text` as a single path, with the left end of its baseline at
+    /// `origin`.
+    pub fn text_path(&self, text: &str, origin: PointF, letter_spacing: f64) -> Path {
+        let scale = self.scale();
+        let mut path = Path::new();
+
+        for (glyph, x) in self.layout(text, letter_spacing) {
+            if let Some(outline) = self.face.outline(glyph) {
+                path.extend(&outline.map_points(|p| {
+                    PointF::new(origin.x + x + p.x * scale, origin.y - p.y * scale)
+                }));
+            }
+        }
+
+        path
+    }
+
+    /// Returns the rectangle of the line box of `text` when drawn with the left end of its
+    /// baseline at `origin`.
+    pub fn line_bounds(&self, text: &str, origin: PointF, letter_spacing: f64) -> RectF {
+        let metrics = self.metrics();
+
+        RectF::new(
+            origin.x,
+            origin.y - metrics.ascent,
+            self.measure(text, letter_spacing),
+            metrics.height(),
+        )
+    }
+
+    fn scale(&self) -> f64 {
+        self.size / self.face.units_per_em()
+    }
+
+    /// Returns each glyph of `text` along with its horizontal offset in points. Characters the
+    /// face does not cover are drawn with its `.notdef` glyph.
+    fn layout<'a>(
+        &'a self,
+        text: &'a str,
+        letter_spacing: f64,
+    ) -> impl Iterator<Item = (GlyphId, f64)> + 'a {
+        let scale = self.scale();
+        let mut previous: Option<GlyphId> = None;
+        let mut x = 0.0;
+
+        text.chars().map(move |c| {
+            let glyph = self.face.glyph_id(c).unwrap_or(0);
+            if let Some(previous) = previous {
+                x += (self.face.advance(previous) + self.face.kerning(previous, glyph)) * scale
+                    + letter_spacing;
+            }
+            previous = Some(glyph);
+
+            (glyph, x)
+        })
+    }
+}
+
+impl Default for Font {
+    /// The regular bundled font at [`DEFAULT_SIZE`].
+    fn default() -> Self {
+        Self::new(bundled_faces()[0].clone(), DEFAULT_SIZE)
+    }
+}
+
+impl fmt::Debug for Font {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Font")
+            .field("family", &self.face.family())
+            .field("weight", &self.face.weight())
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl PartialEq for Font {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.face, &other.face) && self.size == other.size
+    }
+}
+
+fn bundled_faces() -> &'static [Arc<dyn FontFace>] {
+    static FACES: OnceLock<Vec<Arc<dyn FontFace>>> = OnceLock::new();
+
+    FACES.get_or_init(|| {
+        [BUNDLED_REGULAR, BUNDLED_BOLD]
+            .into_iter()
+            .map(|data| {
+                let face = TtfFace::from_bytes(data, 0).expect("bundled fonts are valid");
+                Arc::new(face) as Arc<dyn FontFace>
+            })
+            .collect()
+    })
+}
+
+/// A set of font faces that [`FontDescriptor`]s are resolved against.
+#[derive(Debug, Clone, Default)]
+pub struct FontCollection {
+    faces: Vec<Arc<dyn FontFace>>,
+}
+
+impl FontCollection {
+    /// Creates an empty collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a collection containing the fonts bundled with wunderbar.
+    pub fn bundled() -> Self {
+        Self {
+            faces: bundled_faces().to_vec(),
+        }
+    }
+
+    pub fn add(&mut self, face: Arc<dyn FontFace>) -> &mut Self {
+        self.faces.push(face);
+        self
+    }
+
+    /// Loads the font file at `path` and adds its first face to the collection.
+    pub fn load(&mut self, path: impl AsRef<FsPath>) -> UiResult<&mut Self> {
+        let face = TtfFace::load(path)?;
+
+        Ok(self.add(Arc::new(face)))
+    }
+
+    /// Finds the face of `descriptor`'s family whose weight is closest to the requested one.
+    /// Family names are matched case-insensitively.
+    pub fn resolve(&self, descriptor: &FontDescriptor) -> UiResult<Font> {
+        self.faces
+            .iter()
+            .filter(|face| face.family().eq_ignore_ascii_case(&descriptor.family))
+            .min_by_key(|face| face.weight().0.abs_diff(descriptor.weight.0))
+            .map(|face| Font::new(face.clone(), descriptor.size))
+            .ok_or_else(|| Error::FontNotFound(descriptor.family.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_closest_weight() {
+        let fonts = FontCollection::bundled();
+
+        let regular = fonts
+            .resolve(&FontDescriptor::new("dejavu sans", 12.0).weight(FontWeight::LIGHT))
+            .unwrap();
+        assert_eq!(
+            regular.descriptor(),
+            FontDescriptor::new(DEFAULT_FAMILY, 12.0)
+        );
+
+        let bold = fonts
+            .resolve(&FontDescriptor::new(DEFAULT_FAMILY, 12.0).weight(FontWeight::BLACK))
+            .unwrap();
+        assert_eq!(bold.face().weight(), FontWeight::BOLD);
+
+        assert_eq!(
+            fonts.resolve(&FontDescriptor::new("Comic Sans", 12.0)),
+            Err(Error::FontNotFound("Comic Sans".to_string()))
+        );
+    }
+
+    #[test]
+    fn owned_font_data_matches_static_data() {
+        let bundled = TtfFace::from_bytes(BUNDLED_REGULAR, 0).unwrap();
+        let owned = Arc::new(TtfFace::from_bytes(BUNDLED_REGULAR.to_vec(), 0).unwrap());
+        let glyph = owned.glyph_id('W').unwrap();
+
+        assert_eq!(owned.family(), bundled.family());
+        assert_eq!(owned.advance(glyph), bundled.advance(glyph));
+        assert_eq!(owned.outline(glyph), bundled.outline(glyph));
+    }
+
+    #[test]
+    fn measure_scales_with_size_and_spacing() {
+        let font = Font::default().with_size(20.0);
+        let width = font.measure("Wi-Fi", 0.0);
+
+        assert!(width > 30.0 && width < 60.0, "width {width}");
+        assert_eq!(font.with_size(40.0).measure("Wi-Fi", 0.0), width * 2.0);
+        assert!((font.measure("Wi-Fi", 1.5) - (width + 4.0 * 1.5)).abs() < 1e-9);
+        assert_eq!(font.measure("", 1.5), 0.0);
+
+        let metrics = font.metrics();
+        assert!(metrics.ascent > metrics.descent && metrics.descent > 0.0);
+    }
+
+    #[test]
+    fn text_path_sits_on_baseline() {
+        let font = Font::default();
+        let origin = PointF::new(10.0, 20.0);
+        let bounds = font.text_path("x", origin, 0.0).bounds().unwrap();
+
+        assert!(bounds.min_x() >= origin.x);
+        assert!((bounds.max_y() - origin.y).abs() < 0.5, "bounds {bounds:?}");
+        assert!(font.text_path(" ", origin, 0.0).is_empty());
+    }
+}
@@ -1,5 +1,8 @@
+#[cfg(target_os = "macos")]
 use cocoa::foundation::{NSPoint, NSRect, NSSize};
+#[cfg(target_os = "macos")]
 use core_graphics::geometry::{CGPoint, CGRect, CGSize};
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
 pub struct Point {
@@ -29,12 +32,14 @@ impl std::ops::Sub for Point {
     }
 }
 
+#[cfg(target_os = "macos")]
 impl Into<NSPoint> for Point {
     fn into(self) -> NSPoint {
         NSPoint::new(self.x as f64, self.y as f64)
     }
 }
 
+#[cfg(target_os = "macos")]
 impl Into<CGPoint> for Point {
     fn into(self) -> CGPoint {
         CGPoint::new(self.x as f64, self.y as f64)
@@ -94,6 +99,7 @@ impl std::ops::Add for Size {
     }
 }
 
+#[cfg(target_os = "macos")]
 impl Into<CGSize> for Size {
     fn into(self) -> CGSize {
         CGSize::new(self.width as f64, self.height as f64)
@@ -127,10 +133,11 @@ impl Size {
         self.expand(0, expand_by_height)
     }
 
+    /// Shrinks the size by the given amounts, stopping at zero.
     pub fn contract(self, contract_by_width: usize, contract_by_height: usize) -> Self {
         Self {
-            width: self.width - contract_by_width,
-            height: self.height - contract_by_height,
+            width: self.width.saturating_sub(contract_by_width),
+            height: self.height.saturating_sub(contract_by_height),
         }
     }
 
@@ -181,15 +188,98 @@ impl Bounds {
                 y: y + padding.top,
             },
             size: Size {
-                width: width.checked_sub(padding.left + padding.right).unwrap_or(0),
-                height: height
-                    .checked_sub(padding.top + padding.bottom)
-                    .unwrap_or(0),
+                width: width.saturating_sub(padding.left.saturating_add(padding.right)),
+                height: height.saturating_sub(padding.top.saturating_add(padding.bottom)),
             },
         }
     }
 }
 
+/// Limits on the size of a drawable, passed down the tree as it is laid out.
+///
+/// Each drawable picks a size within its constraints and passes it back up, see
+/// [`Drawable::measure`](super::Drawable::measure). All operations saturate rather than overflow,
+/// and the minimum never exceeds the maximum.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Constraints {
+    pub min: Size,
+    pub max: Size,
+    /// Number of device pixels per point of the canvas the drawable is going to be drawn onto,
+    /// see [`Canvas::scale`](super::canvas::Canvas::scale).
+    pub scale: f64,
+}
+
+impl Constraints {
+    /// Creates constraints between `min` and `max`, at a scale of `1.0`. Minimums larger than the
+    /// maximum are lowered to it.
+    pub fn new(min: Size, max: Size) -> Self {
+        Self {
+            min: Size::new(min.width.min(max.width), min.height.min(max.height)),
+            max,
+            scale: 1.0,
+        }
+    }
+
+    /// Returns the same constraints for a canvas with `scale` pixels per point.
+    pub fn with_scale(self, scale: f64) -> Self {
+        Self { scale, ..self }
+    }
+
+    /// Constraints allowing exactly `size`.
+    pub fn tight(size: Size) -> Self {
+        Self::new(size, size)
+    }
+
+    /// Constraints allowing anything from nothing up to `size`.
+    pub fn loose(size: Size) -> Self {
+        Self::new(Size::zero(), size)
+    }
+
+    /// Returns the size closest to `size` that satisfies the constraints.
+    pub fn constrain(self, size: Size) -> Size {
+        Size::new(
+            size.width.clamp(self.min.width, self.max.width),
+            size.height.clamp(self.min.height, self.max.height),
+        )
+    }
+
+    /// Returns the constraints satisfying both `self` and `other`, at the scale of `self`. Where
+    /// they conflict, the smaller maximum wins.
+    pub fn intersect(self, other: Self) -> Self {
+        Self::new(
+            Size::new(
+                self.min.width.max(other.min.width),
+                self.min.height.max(other.min.height),
+            ),
+            Size::new(
+                self.max.width.min(other.max.width),
+                self.max.height.min(other.max.height),
+            ),
+        )
+        .with_scale(self.scale)
+    }
+
+    /// Shrinks the constraints by `padding`, for laying out the content inside of it.
+    pub fn deflate(self, padding: &Padding) -> Self {
+        let (horizontal, vertical) = (
+            padding.left.saturating_add(padding.right),
+            padding.top.saturating_add(padding.bottom),
+        );
+
+        Self::new(
+            self.min.contract(horizontal, vertical),
+            self.max.contract(horizontal, vertical),
+        )
+        .with_scale(self.scale)
+    }
+
+    /// Whether the constraints allow exactly one size.
+    pub fn is_tight(self) -> bool {
+        self.min == self.max
+    }
+}
+
+#[cfg(target_os = "macos")]
 impl Into<NSRect> for Bounds {
     fn into(self) -> NSRect {
         let Point { x, y } = self.position;
@@ -202,6 +292,7 @@ impl Into<NSRect> for Bounds {
     }
 }
 
+#[cfg(target_os = "macos")]
 impl Into<CGRect> for Bounds {
     fn into(self) -> CGRect {
         let Point { x, y } = self.position;
@@ -214,6 +305,131 @@ impl Into<CGRect> for Bounds {
     }
 }
 
+/// A point with sub-pixel precision, used for vector drawing operations.
+#[derive(Debug, Copy, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PointF {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl PointF {
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Point> for PointF {
+    fn from(value: Point) -> Self {
+        Self::new(value.x as f64, value.y as f64)
+    }
+}
+
+/// A rectangle with sub-pixel precision, used for vector drawing operations.
+#[derive(Debug, Copy, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct RectF {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl RectF {
+    pub const fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn min_x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn min_y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn max_x(&self) -> f64 {
+        self.x + self.width
+    }
+
+    pub fn max_y(&self) -> f64 {
+        self.y + self.height
+    }
+
+    pub fn center(&self) -> PointF {
+        PointF::new(self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+
+    /// Shrinks the rectangle by `dx` on the left and right and `dy` on the top and bottom, never
+    /// past a width or height of zero. Negative values grow it instead.
+    pub fn inset(self, dx: f64, dy: f64) -> Self {
+        let width = (self.width - dx * 2.0).max(0.0);
+        let height = (self.height - dy * 2.0).max(0.0);
+
+        Self::new(
+            self.x + (self.width - width) / 2.0,
+            self.y + (self.height - height) / 2.0,
+            width,
+            height,
+        )
+    }
+
+    pub fn translate(self, dx: f64, dy: f64) -> Self {
+        Self::new(self.x + dx, self.y + dy, self.width, self.height)
+    }
+
+    /// Returns the area covered by both `self` and `other`, which is empty (but not negative) if
+    /// they do not overlap.
+    pub fn intersect(self, other: Self) -> Self {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+
+        Self::new(
+            x,
+            y,
+            (self.max_x().min(other.max_x()) - x).max(0.0),
+            (self.max_y().min(other.max_y()) - y).max(0.0),
+        )
+    }
+
+    /// Returns the smallest rectangle containing both `self` and `other`.
+    pub fn union(self, other: Self) -> Self {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+
+        Self::new(
+            x,
+            y,
+            self.max_x().max(other.max_x()) - x,
+            self.max_y().max(other.max_y()) - y,
+        )
+    }
+}
+
+impl From<Bounds> for RectF {
+    fn from(value: Bounds) -> Self {
+        Self::new(
+            value.position.x as f64,
+            value.position.y as f64,
+            value.size.width as f64,
+            value.size.height as f64,
+        )
+    }
+}
+
+#[cfg(target_os = "macos")]
+impl Into<CGRect> for RectF {
+    fn into(self) -> CGRect {
+        CGRect::new(
+            &CGPoint::new(self.x, self.y),
+            &CGSize::new(self.width, self.height),
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Padding {
     pub left: usize,
@@ -250,3 +466,87 @@ impl Padding {
         }
     }
 }
+
+/// The radius of each corner of a rounded rectangle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct CornerRadius {
+    pub top_left: usize,
+    pub top_right: usize,
+    pub bottom_right: usize,
+    pub bottom_left: usize,
+}
+
+impl CornerRadius {
+    pub fn new(top_left: usize, top_right: usize, bottom_right: usize, bottom_left: usize) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    pub fn uni(radius: usize) -> Self {
+        Self::new(radius, radius, radius, radius)
+    }
+
+    /// Rounds only the left corners, e.g. for the first item of a group.
+    pub fn left(radius: usize) -> Self {
+        Self::new(radius, 0, 0, radius)
+    }
+
+    /// Rounds only the right corners, e.g. for the last item of a group.
+    pub fn right(radius: usize) -> Self {
+        Self::new(0, radius, radius, 0)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the radii in the order expected by [`Path::rounded_rect`], clockwise starting from
+    /// the top-left corner.
+    ///
+    /// [`Path::rounded_rect`]: super::canvas::Path::rounded_rect
+    pub fn radii(&self) -> [f64; 4] {
+        [
+            self.top_left as f64,
+            self.top_right as f64,
+            self.bottom_right as f64,
+            self.bottom_left as f64,
+        ]
+    }
+}
+
+impl From<usize> for CornerRadius {
+    fn from(radius: usize) -> Self {
+        Self::uni(radius)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constraints_deflate_by_padding() {
+        let constraints = Constraints::new(Size::new(20, 10), Size::new(40, 12));
+        let deflated = constraints.deflate(&Padding::new(4, 8, 1, 2));
+
+        assert_eq!(
+            deflated,
+            Constraints::new(Size::new(8, 7), Size::new(28, 9))
+        );
+        assert!(!deflated.is_tight());
+        // Padding larger than the constraints leaves nothing, rather than underflowing.
+        assert_eq!(
+            constraints.deflate(&Padding::uni(usize::MAX)),
+            Constraints::tight(Size::zero())
+        );
+        assert!(Constraints::tight(Size::zero()).is_tight());
+        assert_eq!(
+            constraints.with_scale(2.0).deflate(&Padding::uni(1)).scale,
+            2.0
+        );
+    }
+}
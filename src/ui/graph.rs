@@ -0,0 +1,424 @@
+use std::collections::VecDeque;
+
+use super::{
+    canvas::{Canvas, LineCap, LineJoin, Path, StrokeStyle},
+    color::Color,
+    geometry::{Bounds, PointF, RectF, Size},
+    paint::Paint,
+    Drawable, UiResult,
+};
+
+/// A sparkline of recent samples, such as CPU usage or network throughput.
+///
+/// Samples are kept in a ring buffer of [`Props::capacity`] entries. The newest sample is drawn at
+/// the right edge, and older samples move left until they are dropped. A graph has no intrinsic
+/// size and fills the bounds it is given, so it is usually wrapped in a sized [`Block`].
+///
+/// [`Block`]: super::block::Block
+#[derive(Debug, Clone, PartialEq)]
+pub struct Graph {
+    samples: VecDeque<f64>,
+    props: Props,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Props {
+    pub style: Style,
+    /// Number of samples kept, and the number of slots across the width of the graph.
+    pub capacity: usize,
+    /// Value at the bottom edge of the graph, or [`None`] to use the smallest sample or the
+    /// baseline, whichever is lower.
+    pub min: Option<f64>,
+    /// Value at the top edge of the graph, or [`None`] to use the largest sample or the baseline,
+    /// whichever is higher.
+    pub max: Option<f64>,
+    /// Value that bars and areas extend from.
+    pub baseline: f64,
+    /// Color of the line, or of the bars.
+    pub color: Color,
+    /// Paint underneath the line of an [`Style::Area`] graph, or [`None`] for a translucent
+    /// `color`. Gradients span the bounds of the graph.
+    pub fill: Option<Paint>,
+    pub line_width: f64,
+    /// Horizontal gap between bars of a [`Style::Bars`] graph.
+    pub bar_gap: f64,
+    /// Color of a line drawn across the graph at the baseline, if any.
+    pub baseline_color: Option<Color>,
+}
+
+impl Default for Props {
+    fn default() -> Self {
+        Self {
+            style: Style::default(),
+            capacity: 60,
+            min: None,
+            max: None,
+            baseline: 0.0,
+            color: Color::WHITE,
+            fill: None,
+            line_width: 1.0,
+            bar_gap: 1.0,
+            baseline_color: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+    /// A line connecting the samples.
+    #[default]
+    Line,
+    /// A bar from the baseline to each sample.
+    Bars,
+    /// A line connecting the samples, with the area between it and the baseline filled in.
+    Area,
+}
+
+impl Graph {
+    pub fn new(props: Props) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(props.capacity),
+            props,
+        }
+    }
+
+    /// Records a new sample, dropping the oldest one if the graph is full. Non-finite samples are
+    /// recorded as the baseline.
+    pub fn push(&mut self, sample: f64) {
+        if self.props.capacity == 0 {
+            return;
+        }
+        if self.samples.len() == self.props.capacity {
+            self.samples.pop_front();
+        }
+
+        let sample = if sample.is_finite() {
+            sample
+        } else {
+            self.props.baseline
+        };
+        self.samples.push_back(sample);
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Returns the recorded samples, from oldest to newest.
+    pub fn samples(&self) -> impl Iterator<Item = f64> + '_ {
+        self.samples.iter().copied()
+    }
+
+    pub fn props(&self) -> &Props {
+        &self.props
+    }
+
+    /// Returns the values at the bottom and top edges of the graph.
+    fn range(&self) -> (f64, f64) {
+        let baseline = self.props.baseline;
+        let min = self
+            .props
+            .min
+            .unwrap_or_else(|| self.samples().fold(baseline, f64::min));
+        let max = self
+            .props
+            .max
+            .unwrap_or_else(|| self.samples().fold(baseline, f64::max));
+
+        if max > min {
+            (min, max)
+        } else {
+            (min, min + 1.0)
+        }
+    }
+
+    /// Returns a function mapping a value to its vertical position within `area`. Values outside
+    /// of the range are clamped to the edges.
+    fn scale_y(&self, area: RectF) -> impl Fn(f64) -> f64 {
+        let (min, max) = self.range();
+
+        move |value| {
+            let t = ((value - min) / (max - min)).clamp(0.0, 1.0);
+            area.max_y() - t * area.height
+        }
+    }
+
+    /// Returns the points of the line through the samples, with the newest on the right edge.
+    fn line_points(&self, area: RectF) -> Vec<PointF> {
+        let y = self.scale_y(area);
+        let step = area.width / self.props.capacity.saturating_sub(1).max(1) as f64;
+        let count = self.samples.len();
+
+        self.samples()
+            .enumerate()
+            .map(|(i, sample)| PointF::new(area.max_x() - (count - 1 - i) as f64 * step, y(sample)))
+            .collect()
+    }
+
+    fn line_path(points: &[PointF]) -> Path {
+        let mut path = Path::new();
+        for (i, point) in points.iter().enumerate() {
+            if i == 0 {
+                path.move_to(point.x, point.y);
+            } else {
+                path.line_to(point.x, point.y);
+            }
+        }
+
+        path
+    }
+
+    fn draw_line(&self, canvas: &mut dyn Canvas, area: RectF) {
+        let points = self.line_points(area);
+        if points.len() < 2 {
+            return;
+        }
+
+        if self.props.style == Style::Area {
+            let baseline = self.scale_y(area)(self.props.baseline);
+            let mut fill = Self::line_path(&points);
+            fill.line_to(points[points.len() - 1].x, baseline)
+                .line_to(points[0].x, baseline)
+                .close();
+
+            match &self.props.fill {
+                Some(Paint::Solid(color)) => canvas.set_fill_color(*color),
+                Some(paint) => canvas.set_fill_paint(paint, area),
+                None => canvas.set_fill_color(self.props.color.with_alpha(0.3)),
+            }
+            canvas.fill_path(&fill);
+        }
+
+        canvas.set_stroke_color(self.props.color);
+        canvas.set_stroke_style(&StrokeStyle {
+            width: self.props.line_width,
+            cap: LineCap::Round,
+            join: LineJoin::Round,
+            ..Default::default()
+        });
+        canvas.stroke_path(&Self::line_path(&points));
+    }
+
+    fn draw_bars(&self, canvas: &mut dyn Canvas, area: RectF) {
+        let y = self.scale_y(area);
+        let slot = area.width / self.props.capacity as f64;
+        let width = (slot - self.props.bar_gap).max(0.0);
+        let baseline = y(self.props.baseline);
+        let first_slot = self.props.capacity - self.samples.len();
+
+        canvas.set_fill_color(self.props.color);
+        for (i, sample) in self.samples().enumerate() {
+            let top = y(sample);
+            let x = area.x + (first_slot + i) as f64 * slot + (slot - width) / 2.0;
+            canvas.fill_rect(RectF::new(
+                x,
+                top.min(baseline),
+                width,
+                (top - baseline).abs(),
+            ));
+        }
+    }
+}
+
+impl Drawable for Graph {
+    /// The whole of the bounds.
+    fn content_size(&self, bounds: Bounds) -> Size {
+        bounds.size
+    }
+
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> UiResult<()> {
+        let area: RectF = bounds.into();
+        if self.props.capacity == 0 || area.width <= 0.0 || area.height <= 0.0 {
+            return Ok(());
+        }
+
+        canvas.save();
+        canvas.clip_rect(area);
+        match self.props.style {
+            Style::Line | Style::Area => self.draw_line(canvas, area),
+            Style::Bars => self.draw_bars(canvas, area),
+        }
+
+        if let Some(color) = self.props.baseline_color {
+            let y = self.scale_y(area)(self.props.baseline);
+            let mut line = Path::new();
+            line.move_to(area.min_x(), y).line_to(area.max_x(), y);
+
+            canvas.set_stroke_color(color);
+            canvas.set_stroke_style(&StrokeStyle::default());
+            canvas.stroke_path(&line);
+        }
+        canvas.restore();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        block::{self, Block},
+        canvas::recording::{DisplayItem, RecordingCanvas},
+        geometry::Padding,
+        layout::{self, Direction, Layout},
+        paint::{Gradient, GradientStop},
+        snapshot::{assert_snapshot, reference_path},
+    };
+
+    use super::*;
+
+    fn graph(props: Props, samples: &[f64]) -> Graph {
+        let mut graph = Graph::new(props);
+        for sample in samples {
+            graph.push(*sample);
+        }
+
+        graph
+    }
+
+    #[test]
+    fn keeps_latest_samples() {
+        let graph = graph(
+            Props {
+                capacity: 3,
+                ..Default::default()
+            },
+            &[1.0, 2.0, f64::NAN, 4.0],
+        );
+
+        assert_eq!(graph.samples().collect::<Vec<_>>(), [2.0, 0.0, 4.0]);
+    }
+
+    #[test]
+    fn scales_line_to_range() {
+        let props = Props {
+            capacity: 5,
+            ..Default::default()
+        };
+        let area = RectF::new(0.0, 0.0, 8.0, 10.0);
+
+        // Automatic ranges include the baseline.
+        let auto = graph(props.clone(), &[5.0, 10.0, 7.5]);
+        assert_eq!(
+            auto.line_points(area),
+            [
+                PointF::new(4.0, 5.0),
+                PointF::new(6.0, 0.0),
+                PointF::new(8.0, 2.5)
+            ]
+        );
+
+        let fixed = graph(
+            Props {
+                min: Some(5.0),
+                max: Some(7.5),
+                ..props
+            },
+            &[0.0, 6.25, 100.0],
+        );
+        let heights: Vec<_> = fixed.line_points(area).iter().map(|p| p.y).collect();
+        assert_eq!(heights, [10.0, 5.0, 0.0]);
+    }
+
+    #[test]
+    fn bars_extend_from_baseline() {
+        let graph = graph(
+            Props {
+                style: Style::Bars,
+                capacity: 4,
+                min: Some(-1.0),
+                max: Some(1.0),
+                bar_gap: 2.0,
+                ..Default::default()
+            },
+            &[1.0, -0.5],
+        );
+        let bars: Vec<_> = RecordingCanvas::record(&graph, Bounds::new(0, 0, 16, 10))
+            .unwrap()
+            .into_iter()
+            .filter_map(|item| match item {
+                DisplayItem::FillRect(rect) => Some(rect),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(
+            bars,
+            [
+                RectF::new(9.0, 0.0, 2.0, 5.0),
+                RectF::new(13.0, 5.0, 2.0, 2.5)
+            ]
+        );
+    }
+
+    #[test]
+    fn graph_styles() {
+        let samples: Vec<_> = (0..24)
+            .map(|i| {
+                let t = i as f64 / 3.0;
+                50.0 + 30.0 * t.sin() + 10.0 * (t * 2.7).cos()
+            })
+            .collect();
+        let tile = |props| {
+            Block::new(
+                graph(props, &samples),
+                block::Props {
+                    width: Some(48),
+                    height: Some(24),
+                    padding: Some(Padding::uni(2)),
+                    ..Default::default()
+                },
+            )
+        };
+        let range = Props {
+            capacity: 24,
+            min: Some(0.0),
+            max: Some(100.0),
+            ..Default::default()
+        };
+
+        let row = Layout::with_children(
+            vec![
+                tile(Props {
+                    baseline: 50.0,
+                    baseline_color: Some(Color::WHITE.with_alpha(0.3)),
+                    ..range.clone()
+                }),
+                tile(Props {
+                    style: Style::Bars,
+                    color: Color::rgb(0.3, 0.7, 1.0),
+                    ..range.clone()
+                }),
+                tile(Props {
+                    style: Style::Area,
+                    color: Color::rgb(0.2, 0.9, 0.4),
+                    fill: Some(
+                        Gradient::linear(
+                            180.0,
+                            vec![
+                                GradientStop::new(0.0, Color::rgba(0.2, 0.9, 0.4, 0.6)),
+                                GradientStop::new(1.0, Color::rgba(0.2, 0.9, 0.4, 0.0)),
+                            ],
+                        )
+                        .into(),
+                    ),
+                    line_width: 1.5,
+                    ..range
+                }),
+            ],
+            layout::Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+        let bar = Block::new(
+            row,
+            block::Props {
+                background_color: Some(Color::rgb(0.12, 0.12, 0.14).into()),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(&bar, Bounds::new(0, 0, 144, 24), reference_path("graph"));
+    }
+}
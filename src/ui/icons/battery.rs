@@ -0,0 +1,340 @@
+use crate::ui::{
+    canvas::{Canvas, FillRule, Path, StrokeStyle},
+    color::Color,
+    geometry::{Bounds, PointF, RectF, Size},
+    Drawable, UiResult,
+};
+
+use super::{fitted_size, Frame};
+
+/// Size of the icon in design units, including the terminal nub.
+const WIDTH: f64 = 24.5;
+const HEIGHT: f64 = 12.0;
+/// Outer edge of the battery's body.
+const BODY: RectF = RectF {
+    x: 0.0,
+    y: 0.0,
+    width: 22.0,
+    height: 12.0,
+};
+const BODY_RADIUS: f64 = 4.0;
+const OUTLINE_WIDTH: f64 = 1.0;
+const NUB: RectF = RectF {
+    x: 23.0,
+    y: 4.0,
+    width: 1.5,
+    height: 4.0,
+};
+/// Area covered by the fill level when fully charged.
+const LEVEL: RectF = RectF {
+    x: 2.0,
+    y: 2.0,
+    width: 18.0,
+    height: 8.0,
+};
+const LEVEL_RADIUS: f64 = 2.0;
+/// Vertices of the charging bolt, centered on the body.
+const BOLT: [(f64, f64); 6] = [
+    (12.5, 0.5),
+    (6.5, 7.0),
+    (10.25, 7.0),
+    (9.5, 11.5),
+    (15.5, 5.0),
+    (11.75, 5.0),
+];
+/// How much larger than the bolt the gap cut into the fill level around it is.
+const BOLT_KNOCKOUT_SCALE: f64 = 1.3;
+
+/// A battery glyph whose fill level follows the charge, in the style of the macOS menu bar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatteryIcon {
+    status: Status,
+    props: Props,
+}
+
+/// The state of the battery shown by a [`BatteryIcon`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Status {
+    /// Charge from `0.0` (empty) to `1.0` (full). Values outside that range are clamped.
+    pub charge: f64,
+    /// Whether the battery is charging, which is shown with a bolt.
+    pub charging: bool,
+    pub low_power_mode: bool,
+}
+
+impl Status {
+    pub fn new(charge: f64) -> Self {
+        Self {
+            charge,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Props {
+    pub outline_color: Color,
+    /// Color of the fill level in the normal state.
+    pub fill_color: Color,
+    /// Color of the fill level when the charge is at or below `low_threshold` and the battery is
+    /// not charging.
+    pub low_color: Color,
+    pub low_threshold: f64,
+    /// Color of the fill level in low power mode, unless the battery is charging.
+    pub low_power_color: Color,
+    /// Color of the fill level while charging.
+    pub charging_color: Color,
+    pub bolt_color: Color,
+}
+
+impl Default for Props {
+    fn default() -> Self {
+        Self {
+            outline_color: Color::WHITE.with_alpha(0.5),
+            fill_color: Color::WHITE,
+            low_color: Color::rgb(1.0, 0.27, 0.23),
+            low_threshold: 0.2,
+            low_power_color: Color::rgb(1.0, 0.8, 0.0),
+            charging_color: Color::rgb(0.2, 0.78, 0.35),
+            bolt_color: Color::WHITE,
+        }
+    }
+}
+
+impl BatteryIcon {
+    pub fn new(status: Status, props: Props) -> Self {
+        Self { status, props }
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    pub fn set_status(&mut self, status: Status) {
+        self.status = status;
+    }
+
+    pub fn props(&self) -> &Props {
+        &self.props
+    }
+
+    fn charge(&self) -> f64 {
+        if self.status.charge.is_nan() {
+            0.0
+        } else {
+            self.status.charge.clamp(0.0, 1.0)
+        }
+    }
+
+    /// Returns the color the fill level is drawn in for the current status.
+    fn level_color(&self) -> Color {
+        if self.status.charging {
+            self.props.charging_color
+        } else if self.status.low_power_mode {
+            self.props.low_power_color
+        } else if self.charge() <= self.props.low_threshold {
+            self.props.low_color
+        } else {
+            self.props.fill_color
+        }
+    }
+
+    fn draw_level(&self, canvas: &mut dyn Canvas, frame: &Frame) {
+        let width = LEVEL.width * self.charge();
+        if width <= 0.0 {
+            return;
+        }
+
+        canvas.save();
+        if self.status.charging {
+            // Cut a gap into the level around the bolt so the two stay distinguishable.
+            let mut knockout = Path::rect(BODY);
+            knockout.extend(&bolt_path(BOLT_KNOCKOUT_SCALE));
+            knockout.set_fill_rule(FillRule::EvenOdd);
+            canvas.clip_path(&frame.path(&knockout));
+        }
+        canvas.set_fill_color(self.level_color());
+        canvas.fill_path(&frame.path(&Path::rounded_rect(
+            RectF::new(LEVEL.x, LEVEL.y, width, LEVEL.height),
+            [LEVEL_RADIUS; 4],
+        )));
+        canvas.restore();
+    }
+}
+
+impl Drawable for BatteryIcon {
+    /// The height of the bounds, and the width matching the icon's aspect ratio.
+    fn content_size(&self, bounds: Bounds) -> Size {
+        fitted_size(WIDTH, HEIGHT, bounds)
+    }
+
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> UiResult<()> {
+        let frame = Frame::fit(WIDTH, HEIGHT, bounds);
+        let inset = OUTLINE_WIDTH / 2.0;
+
+        canvas.save();
+        canvas.set_stroke_color(self.props.outline_color);
+        canvas.set_stroke_style(&StrokeStyle {
+            width: frame.length(OUTLINE_WIDTH),
+            ..Default::default()
+        });
+        canvas.stroke_path(&frame.path(&Path::rounded_rect(
+            BODY.inset(inset, inset),
+            [BODY_RADIUS - inset; 4],
+        )));
+
+        canvas.set_fill_color(self.props.outline_color);
+        canvas.fill_path(&frame.path(&Path::rounded_rect(NUB, [0.0, NUB.width, NUB.width, 0.0])));
+
+        self.draw_level(canvas, &frame);
+
+        if self.status.charging {
+            canvas.set_fill_color(self.props.bolt_color);
+            canvas.fill_path(&frame.path(&bolt_path(1.0)));
+        }
+        canvas.restore();
+
+        Ok(())
+    }
+}
+
+/// Returns the bolt outline scaled by `scale` around the center of the body.
+fn bolt_path(scale: f64) -> Path {
+    let center = BODY.center();
+    let mut path = Path::new();
+    for (i, (x, y)) in BOLT.into_iter().enumerate() {
+        let point = PointF::new(
+            center.x + (x - center.x) * scale,
+            center.y + (y - center.y) * scale,
+        );
+        if i == 0 {
+            path.move_to(point.x, point.y);
+        } else {
+            path.line_to(point.x, point.y);
+        }
+    }
+    path.close();
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        block::{self, Block},
+        canvas::recording::{DisplayItem, RecordingCanvas},
+        layout::{self, Direction, Layout},
+        snapshot::{assert_snapshot, reference_path},
+    };
+
+    use super::*;
+
+    fn filled(icon: &BatteryIcon, bounds: Bounds) -> Vec<(Color, RectF)> {
+        let list = RecordingCanvas::record(icon, bounds).unwrap();
+        let mut color = Color::TRANSPARENT;
+
+        list.into_iter()
+            .filter_map(|item| match item {
+                DisplayItem::SetFillColor(fill) => {
+                    color = fill;
+                    None
+                }
+                DisplayItem::FillPath(path) => Some((color, path.bounds().unwrap())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sizes_to_height() {
+        let icon = BatteryIcon::new(Status::new(1.0), Props::default());
+
+        assert_eq!(
+            icon.content_size(Bounds::new(0, 0, 100, 24)),
+            Size::new(49, 24)
+        );
+        assert_eq!(
+            icon.content_size(Bounds::new(0, 0, 49, 100)),
+            Size::new(49, 24)
+        );
+    }
+
+    #[test]
+    fn level_follows_charge() {
+        let props = Props::default();
+        let bounds = Bounds::new(10, 0, 100, 24);
+        let level = |status| filled(&BatteryIcon::new(status, props.clone()), bounds)[1];
+
+        assert_eq!(
+            level(Status::new(0.5)),
+            (props.fill_color, RectF::new(14.0, 4.0, 18.0, 16.0))
+        );
+        assert_eq!(level(Status::new(0.1)).0, props.low_color);
+        assert_eq!(level(Status::new(1.5)).1.width, 36.0);
+        assert_eq!(
+            level(Status {
+                low_power_mode: true,
+                ..Status::new(0.1)
+            })
+            .0,
+            props.low_power_color
+        );
+
+        let charging = filled(
+            &BatteryIcon::new(
+                Status {
+                    charging: true,
+                    ..Status::new(0.1)
+                },
+                props.clone(),
+            ),
+            bounds,
+        );
+        assert_eq!(charging[1].0, props.charging_color);
+        assert_eq!(charging[2].0, props.bolt_color);
+
+        // Nothing but the nub is filled when empty.
+        assert_eq!(
+            filled(&BatteryIcon::new(Status::new(0.0), props), bounds).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn battery_states() {
+        let icon = |status| BatteryIcon::new(status, Props::default());
+        let row = Layout::with_children(
+            vec![
+                icon(Status::new(1.0)),
+                icon(Status::new(0.55)),
+                icon(Status::new(0.1)),
+                icon(Status {
+                    low_power_mode: true,
+                    ..Status::new(0.4)
+                }),
+                icon(Status {
+                    charging: true,
+                    ..Status::new(0.7)
+                }),
+            ],
+            layout::Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+
+        let bar = Block::new(
+            row,
+            block::Props {
+                background_color: Some(Color::rgb(0.12, 0.12, 0.14).into()),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &bar,
+            Bounds::new(0, 0, 245, 24),
+            reference_path("battery_icon"),
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use crate::ui::{
+    canvas::{Canvas, LineCap, Path, StrokeStyle},
+    color::Color,
+    geometry::{Bounds, PointF, Size},
+    Drawable, UiResult,
+};
+
+use super::{fitted_size, Frame};
+
+/// Size of the icon in design units.
+const SIZE: f64 = 12.0;
+const CENTER: PointF = PointF { x: 6.0, y: 6.0 };
+const FACE_RADIUS: f64 = 6.0;
+/// Distance from the center at which the ticks end.
+const TICK_OUTER: f64 = 5.0;
+/// Lengths of the ticks at the quarter hours and at the other hours.
+const QUARTER_TICK_LENGTH: f64 = 1.25;
+const HOUR_TICK_LENGTH: f64 = 0.75;
+const TICK_WIDTH: f64 = 0.5;
+const HOUR_HAND_LENGTH: f64 = 2.75;
+const HOUR_HAND_WIDTH: f64 = 1.0;
+const MINUTE_HAND_LENGTH: f64 = 4.25;
+const MINUTE_HAND_WIDTH: f64 = 0.75;
+const HUB_RADIUS: f64 = 0.75;
+
+/// A small analog clock face showing a given time with hour and minute hands.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalogClockIcon {
+    time: Time,
+    props: Props,
+}
+
+/// A time of day shown by an [`AnalogClockIcon`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Time {
+    /// Hour of the day. Hours past 12 wrap around the face.
+    pub hour: u32,
+    pub minute: u32,
+}
+
+impl Time {
+    pub fn new(hour: u32, minute: u32) -> Self {
+        Self { hour, minute }
+    }
+
+    /// Angle of the hour hand in degrees, clockwise from 12 o'clock. The hand moves gradually
+    /// between hours as the minutes pass.
+    pub fn hour_angle(&self) -> f64 {
+        (self.hour % 12) as f64 * 30.0 + (self.minute % 60) as f64 * 0.5
+    }
+
+    /// Angle of the minute hand in degrees, clockwise from 12 o'clock.
+    pub fn minute_angle(&self) -> f64 {
+        (self.minute % 60) as f64 * 6.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Props {
+    pub face_color: Color,
+    /// Color of the hour marks around the edge of the face, or [`None`] to leave them out.
+    pub tick_color: Option<Color>,
+    /// Color of both hands and the hub joining them.
+    pub hand_color: Color,
+}
+
+impl Default for Props {
+    fn default() -> Self {
+        Self {
+            face_color: Color::WHITE,
+            tick_color: Some(Color::BLACK.with_alpha(0.5)),
+            hand_color: Color::BLACK,
+        }
+    }
+}
+
+impl AnalogClockIcon {
+    pub fn new(time: Time, props: Props) -> Self {
+        Self { time, props }
+    }
+
+    pub fn time(&self) -> Time {
+        self.time
+    }
+
+    pub fn set_time(&mut self, time: Time) {
+        self.time = time;
+    }
+
+    pub fn props(&self) -> &Props {
+        &self.props
+    }
+
+    fn draw_ticks(&self, canvas: &mut dyn Canvas, frame: &Frame, color: Color) {
+        let mut ticks = Path::new();
+        for hour in 0..12 {
+            let length = if hour % 3 == 0 {
+                QUARTER_TICK_LENGTH
+            } else {
+                HOUR_TICK_LENGTH
+            };
+            let angle = hour as f64 * 30.0;
+            let (start, end) = (
+                dial_point(TICK_OUTER - length, angle),
+                dial_point(TICK_OUTER, angle),
+            );
+            ticks.move_to(start.x, start.y).line_to(end.x, end.y);
+        }
+
+        canvas.set_stroke_color(color);
+        canvas.set_stroke_style(&StrokeStyle {
+            width: frame.length(TICK_WIDTH),
+            ..Default::default()
+        });
+        canvas.stroke_path(&frame.path(&ticks));
+    }
+
+    fn draw_hand(
+        &self,
+        canvas: &mut dyn Canvas,
+        frame: &Frame,
+        angle: f64,
+        length: f64,
+        width: f64,
+    ) {
+        let end = dial_point(length, angle);
+        let mut hand = Path::new();
+        hand.move_to(CENTER.x, CENTER.y).line_to(end.x, end.y);
+
+        canvas.set_stroke_style(&StrokeStyle {
+            width: frame.length(width),
+            cap: LineCap::Round,
+            ..Default::default()
+        });
+        canvas.stroke_path(&frame.path(&hand));
+    }
+}
+
+impl Drawable for AnalogClockIcon {
+    /// A square as tall as the bounds, or as wide if they are narrower.
+    fn content_size(&self, bounds: Bounds) -> Size {
+        fitted_size(SIZE, SIZE, bounds)
+    }
+
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> UiResult<()> {
+        let frame = Frame::fit(SIZE, SIZE, bounds);
+
+        canvas.save();
+        canvas.set_fill_color(self.props.face_color);
+        canvas.fill_path(&frame.path(&Path::circle(CENTER, FACE_RADIUS)));
+
+        if let Some(tick_color) = self.props.tick_color {
+            self.draw_ticks(canvas, &frame, tick_color);
+        }
+
+        canvas.set_stroke_color(self.props.hand_color);
+        self.draw_hand(
+            canvas,
+            &frame,
+            self.time.hour_angle(),
+            HOUR_HAND_LENGTH,
+            HOUR_HAND_WIDTH,
+        );
+        self.draw_hand(
+            canvas,
+            &frame,
+            self.time.minute_angle(),
+            MINUTE_HAND_LENGTH,
+            MINUTE_HAND_WIDTH,
+        );
+
+        canvas.set_fill_color(self.props.hand_color);
+        canvas.fill_path(&frame.path(&Path::circle(CENTER, HUB_RADIUS)));
+        canvas.restore();
+
+        Ok(())
+    }
+}
+
+/// Returns the point `radius` away from the center of the face, `angle` degrees clockwise from 12
+/// o'clock.
+fn dial_point(radius: f64, angle: f64) -> PointF {
+    let (sin, cos) = angle.to_radians().sin_cos();
+
+    PointF::new(CENTER.x + radius * sin, CENTER.y - radius * cos)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        block::{self, Block},
+        canvas::{
+            recording::{DisplayItem, RecordingCanvas},
+            PathElement,
+        },
+        layout::{self, Direction, Layout},
+        snapshot::{assert_snapshot, reference_path},
+    };
+
+    use super::*;
+
+    #[test]
+    fn hand_angles() {
+        assert_eq!(Time::new(0, 0).hour_angle(), 0.0);
+        assert_eq!(Time::new(3, 0).hour_angle(), 90.0);
+        assert_eq!(Time::new(15, 30).hour_angle(), 105.0);
+        assert_eq!(Time::new(15, 30).minute_angle(), 180.0);
+        assert_eq!(Time::new(23, 45).minute_angle(), 270.0);
+    }
+
+    #[test]
+    fn hands_point_at_time() {
+        let icon = AnalogClockIcon::new(
+            Time::new(9, 0),
+            Props {
+                tick_color: None,
+                ..Default::default()
+            },
+        );
+        let hand_ends: Vec<_> = RecordingCanvas::record(&icon, Bounds::new(0, 0, 100, 24))
+            .unwrap()
+            .into_iter()
+            .filter_map(|item| match item {
+                DisplayItem::StrokePath(path) => match path.elements() {
+                    [_, PathElement::LineTo(end)] => Some(*end),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+
+        // The icon is scaled by 2: the hour hand points left, the minute hand up.
+        assert_eq!(hand_ends.len(), 2);
+        assert!((hand_ends[0].x - (12.0 - 2.0 * HOUR_HAND_LENGTH)).abs() < 1e-9);
+        assert!((hand_ends[0].y - 12.0).abs() < 1e-9);
+        assert!((hand_ends[1].x - 12.0).abs() < 1e-9);
+        assert!((hand_ends[1].y - (12.0 - 2.0 * MINUTE_HAND_LENGTH)).abs() < 1e-9);
+        assert_eq!(
+            icon.content_size(Bounds::new(0, 0, 100, 24)),
+            Size::new(24, 24)
+        );
+    }
+
+    #[test]
+    fn clock_faces() {
+        let clock = |hour, minute, props| AnalogClockIcon::new(Time::new(hour, minute), props);
+        let row = Layout::with_children(
+            vec![
+                clock(10, 10, Props::default()),
+                clock(15, 45, Props::default()),
+                clock(
+                    6,
+                    30,
+                    Props {
+                        face_color: Color::rgb(0.2, 0.2, 0.25),
+                        tick_color: None,
+                        hand_color: Color::rgb(1.0, 0.6, 0.1),
+                    },
+                ),
+            ],
+            layout::Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+        let bar = Block::new(
+            row,
+            block::Props {
+                background_color: Some(Color::rgb(0.12, 0.12, 0.14).into()),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &bar,
+            Bounds::new(0, 0, 72, 24),
+            reference_path("clock_icon"),
+        );
+    }
+}
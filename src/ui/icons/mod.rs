@@ -0,0 +1,74 @@
+//! Status icons drawn procedurally with vector paths, so they stay sharp at any size and can
+//! reflect live values such as the battery charge.
+//!
+//! Each icon is designed on a small fixed grid and scaled uniformly to the height it is given,
+//! sizing itself by its aspect ratio.
+
+use super::{
+    canvas::Path,
+    geometry::{Bounds, PointF, RectF, Size},
+};
+
+pub mod battery;
+pub mod clock;
+pub mod wifi;
+
+/// Maps the design coordinates of an icon onto the area it is drawn in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Frame {
+    scale: f64,
+    origin: PointF,
+}
+
+impl Frame {
+    /// Places a `width` by `height` design at the position of `bounds`, scaled to its
+    /// [`fitted_size`] and centered within it.
+    pub(crate) fn fit(width: f64, height: f64, bounds: Bounds) -> Self {
+        let size = fitted_size(width, height, bounds);
+        let area = RectF::new(
+            bounds.position.x as f64,
+            bounds.position.y as f64,
+            size.width as f64,
+            size.height as f64,
+        );
+        let scale = (area.width / width).min(area.height / height);
+        let center = area.center();
+
+        Self {
+            scale,
+            origin: PointF::new(
+                center.x - width * scale / 2.0,
+                center.y - height * scale / 2.0,
+            ),
+        }
+    }
+
+    /// Maps a point from design coordinates.
+    pub(crate) fn point(&self, x: f64, y: f64) -> PointF {
+        PointF::new(
+            self.origin.x + x * self.scale,
+            self.origin.y + y * self.scale,
+        )
+    }
+
+    /// Maps a length, such as a stroke width, from design units.
+    pub(crate) fn length(&self, length: f64) -> f64 {
+        length * self.scale
+    }
+
+    /// Maps every point of `path` from design coordinates.
+    pub(crate) fn path(&self, path: &Path) -> Path {
+        path.map_points(|p| self.point(p.x, p.y))
+    }
+}
+
+/// Returns the size of a `width` by `height` design scaled to the height of `bounds`, or smaller
+/// if it would not fit their width, rounded up to whole points.
+pub(crate) fn fitted_size(width: f64, height: f64, bounds: Bounds) -> Size {
+    let scale = (bounds.size.width as f64 / width).min(bounds.size.height as f64 / height);
+
+    Size::new(
+        ((width * scale).ceil() as usize).min(bounds.size.width),
+        ((height * scale).ceil() as usize).min(bounds.size.height),
+    )
+}
@@ -0,0 +1,279 @@
+use crate::ui::{
+    canvas::{Canvas, FillRule, Path},
+    color::Color,
+    geometry::{Bounds, PointF, RectF, Size},
+    Drawable, UiResult,
+};
+
+use super::{fitted_size, Frame};
+
+/// Size of the icon in design units.
+const WIDTH: f64 = 17.0;
+const HEIGHT: f64 = 12.0;
+/// Point the fan spreads out from, at the bottom center.
+const APEX: PointF = PointF { x: 8.5, y: 11.75 };
+const RADIUS: f64 = 11.5;
+/// Angle of either edge of the fan, in degrees from the vertical.
+const SPREAD: f64 = 45.0;
+/// Width of the gap between bars, relative to the width of a bar.
+const GAP_RATIO: f64 = 0.6;
+/// Ends of the line crossing out the fan when disconnected.
+const SLASH: (PointF, PointF) = (PointF { x: 2.0, y: 0.5 }, PointF { x: 15.0, y: 11.5 });
+const SLASH_WIDTH: f64 = 1.5;
+/// Width of the gap cut into the bars on either side of the slash.
+const SLASH_GAP: f64 = 1.25;
+
+/// A Wi-Fi glyph made of a fan of arcs, filled according to the signal strength.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WifiIcon {
+    status: Status,
+    props: Props,
+}
+
+/// The state of the connection shown by a [`WifiIcon`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Status {
+    /// Connected with a signal `strength` from `0.0` to `1.0`, shown by filling that fraction of
+    /// the bars, rounded up.
+    Connected { strength: f64 },
+    /// Looking for a network to join. All bars are dimmed.
+    Searching,
+    /// Not connected. All bars are dimmed and crossed out.
+    #[default]
+    Disconnected,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Props {
+    /// Number of bars in the fan, including the wedge at its base.
+    pub bars: usize,
+    /// Color of the bars representing the current signal strength.
+    pub active_color: Color,
+    /// Color of the remaining bars.
+    pub inactive_color: Color,
+    /// Color of the line crossing out the fan when disconnected.
+    pub cross_color: Color,
+}
+
+impl Default for Props {
+    fn default() -> Self {
+        Self {
+            bars: 3,
+            active_color: Color::WHITE,
+            inactive_color: Color::WHITE.with_alpha(0.3),
+            cross_color: Color::WHITE,
+        }
+    }
+}
+
+impl WifiIcon {
+    pub fn new(status: Status, props: Props) -> Self {
+        Self { status, props }
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    pub fn set_status(&mut self, status: Status) {
+        self.status = status;
+    }
+
+    pub fn props(&self) -> &Props {
+        &self.props
+    }
+
+    /// Number of bars drawn in the active color.
+    fn active_bars(&self) -> usize {
+        match self.status {
+            Status::Connected { strength } if strength > 0.0 => {
+                let bars = self.props.bars as f64;
+                (strength.min(1.0) * bars).ceil() as usize
+            }
+            _ => 0,
+        }
+    }
+
+    /// Returns the inner and outer radius of each bar, from the base of the fan outwards.
+    fn bar_radii(&self) -> Vec<(f64, f64)> {
+        let bars = self.props.bars as f64;
+        let width = RADIUS / (bars + GAP_RATIO * (bars - 1.0));
+
+        (0..self.props.bars)
+            .map(|i| {
+                let inner = i as f64 * width * (1.0 + GAP_RATIO);
+                (inner, inner + width)
+            })
+            .collect()
+    }
+}
+
+impl Drawable for WifiIcon {
+    /// The height of the bounds, and the width matching the icon's aspect ratio.
+    fn content_size(&self, bounds: Bounds) -> Size {
+        fitted_size(WIDTH, HEIGHT, bounds)
+    }
+
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> UiResult<()> {
+        let frame = Frame::fit(WIDTH, HEIGHT, bounds);
+        let crossed_out = self.status == Status::Disconnected;
+        let active_bars = self.active_bars();
+
+        canvas.save();
+        if crossed_out {
+            let mut knockout = Path::rect(RectF::new(0.0, 0.0, WIDTH, HEIGHT));
+            knockout.extend(&slash_outline(SLASH_WIDTH / 2.0 + SLASH_GAP));
+            knockout.set_fill_rule(FillRule::EvenOdd);
+            canvas.clip_path(&frame.path(&knockout));
+        }
+        for (i, (inner, outer)) in self.bar_radii().into_iter().enumerate() {
+            canvas.set_fill_color(if i < active_bars {
+                self.props.active_color
+            } else {
+                self.props.inactive_color
+            });
+            canvas.fill_path(&frame.path(&bar_path(inner, outer)));
+        }
+        canvas.restore();
+
+        if crossed_out {
+            canvas.save();
+            canvas.set_fill_color(self.props.cross_color);
+            canvas.fill_path(&frame.path(&slash_outline(SLASH_WIDTH / 2.0)));
+            canvas.restore();
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the outline of a bar between `inner` and `outer` radius. The innermost bar is a wedge.
+fn bar_path(inner: f64, outer: f64) -> Path {
+    let mut path = Path::new();
+    path.arc(APEX, outer, -SPREAD, SPREAD * 2.0);
+
+    if inner > 0.0 {
+        path.arc(APEX, inner, SPREAD, -SPREAD * 2.0);
+    } else {
+        path.line_to(APEX.x, APEX.y);
+    }
+    path.close();
+
+    path
+}
+
+/// Returns the outline of the slash, extended by `half_width` on either side of its center line.
+fn slash_outline(half_width: f64) -> Path {
+    let (from, to) = SLASH;
+    let length = (to.x - from.x).hypot(to.y - from.y);
+    let (nx, ny) = (
+        -(to.y - from.y) / length * half_width,
+        (to.x - from.x) / length * half_width,
+    );
+
+    let mut path = Path::new();
+    path.move_to(from.x + nx, from.y + ny)
+        .line_to(to.x + nx, to.y + ny)
+        .line_to(to.x - nx, to.y - ny)
+        .line_to(from.x - nx, from.y - ny)
+        .close();
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        block::{self, Block},
+        canvas::recording::{DisplayItem, RecordingCanvas},
+        layout::{self, Direction, Layout},
+        snapshot::{assert_snapshot, reference_path},
+    };
+
+    use super::*;
+
+    fn fill_colors(icon: &WifiIcon) -> Vec<Color> {
+        RecordingCanvas::record(icon, Bounds::new(0, 0, 34, 24))
+            .unwrap()
+            .into_iter()
+            .filter_map(|item| match item {
+                DisplayItem::SetFillColor(color) => Some(color),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fills_bars_by_strength() {
+        let props = Props::default();
+        let (on, off) = (props.active_color, props.inactive_color);
+        let colors = |status| fill_colors(&WifiIcon::new(status, props.clone()));
+
+        assert_eq!(colors(Status::Connected { strength: 0.5 }), [on, on, off]);
+        assert_eq!(colors(Status::Connected { strength: 1.0 }), [on, on, on]);
+        assert_eq!(colors(Status::Connected { strength: 0.0 }), [off, off, off]);
+        assert_eq!(colors(Status::Searching), [off, off, off]);
+        assert_eq!(
+            colors(Status::Disconnected),
+            [off, off, off, props.cross_color]
+        );
+
+        let five_bars = WifiIcon::new(
+            Status::Connected { strength: 0.3 },
+            Props {
+                bars: 5,
+                ..Props::default()
+            },
+        );
+        assert_eq!(fill_colors(&five_bars), [on, on, off, off, off]);
+    }
+
+    #[test]
+    fn bars_span_fan() {
+        let icon = WifiIcon::new(Status::Searching, Props::default());
+        let radii = icon.bar_radii();
+
+        assert_eq!(radii[0].0, 0.0);
+        assert!((radii[2].1 - RADIUS).abs() < 1e-9);
+        assert_eq!(
+            icon.content_size(Bounds::new(0, 0, 100, 24)),
+            Size::new(34, 24)
+        );
+
+        // The outermost bar spans the full width of the fan.
+        let outer = bar_path(radii[2].0, radii[2].1).bounds().unwrap();
+        let width = 2.0 * RADIUS * SPREAD.to_radians().sin();
+        assert!((outer.width - width).abs() < 1e-9, "{outer:?}");
+    }
+
+    #[test]
+    fn wifi_states() {
+        let icon = |status| WifiIcon::new(status, Props::default());
+        let row = Layout::with_children(
+            vec![
+                icon(Status::Connected { strength: 1.0 }),
+                icon(Status::Connected { strength: 0.6 }),
+                icon(Status::Connected { strength: 0.2 }),
+                icon(Status::Searching),
+                icon(Status::Disconnected),
+            ],
+            layout::Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+        let bar = Block::new(
+            row,
+            block::Props {
+                background_color: Some(Color::rgb(0.12, 0.12, 0.14).into()),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &bar,
+            Bounds::new(0, 0, 170, 24),
+            reference_path("wifi_icon"),
+        );
+    }
+}
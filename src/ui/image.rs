@@ -0,0 +1,310 @@
+use std::{path::Path as FsPath, sync::Arc};
+
+use super::{
+    canvas::{Bitmap, Canvas, Path},
+    color::Color,
+    geometry::{Bounds, Constraints, CornerRadius, Point, RectF, Size},
+    Drawable, UiResult,
+};
+
+/// A raster image, such as an app icon or album art.
+///
+/// Every pixel of the image covers a single device pixel at its intrinsic size, so it is as large
+/// in points as its pixel size divided by the [`Canvas::scale`] it is drawn onto. Images made for
+/// a Retina display are therefore half as large in points there, and stay crisp.
+pub struct Image {
+    bitmap: Arc<Bitmap>,
+    props: Props,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Props {
+    pub fit: Fit,
+    /// Rounds the corners of the visible part of the image.
+    pub corner_radius: Option<CornerRadius>,
+    /// Replaces the color of every pixel, keeping only the image's transparency. Intended for
+    /// monochrome icons.
+    pub tint: Option<Color>,
+}
+
+/// How an [`Image`] is sized and positioned within its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Fit {
+    /// Scaled to be as large as possible while staying entirely within the bounds, keeping its
+    /// aspect ratio, and centered.
+    #[default]
+    Contain,
+    /// Scaled to cover the bounds entirely, keeping its aspect ratio, centered and cropped.
+    Cover,
+    /// Scaled to exactly fill the bounds, ignoring its aspect ratio.
+    Stretch,
+    /// Kept at its intrinsic size, centered and cropped.
+    Center,
+}
+
+impl Image {
+    pub fn new(bitmap: impl Into<Arc<Bitmap>>, props: Props) -> Self {
+        let bitmap = bitmap.into();
+        let bitmap = match props.tint {
+            Some(tint) => Arc::new(bitmap.tinted(tint)),
+            None => bitmap,
+        };
+
+        Self { bitmap, props }
+    }
+
+    /// Loads the PNG or JPEG image at `path`.
+    pub fn load(path: impl AsRef<FsPath>, props: Props) -> UiResult<Self> {
+        Ok(Self::new(Bitmap::load(path)?, props))
+    }
+
+    /// Decodes a PNG or JPEG image from `bytes`.
+    pub fn from_bytes(bytes: &[u8], props: Props) -> UiResult<Self> {
+        Ok(Self::new(Bitmap::decode(bytes)?, props))
+    }
+
+    /// Size of the image in points on a canvas with `scale` pixels per point.
+    pub fn intrinsic_size(&self, scale: f64) -> (f64, f64) {
+        let scale = if scale > 0.0 { scale } else { 1.0 };
+
+        (
+            self.bitmap.width() as f64 / scale,
+            self.bitmap.height() as f64 / scale,
+        )
+    }
+
+    /// Size of the image on a canvas with `scale` pixels per point, rounded up to whole points.
+    fn size(&self, scale: f64) -> Size {
+        let (width, height) = self.intrinsic_size(scale);
+
+        Size::new(width.ceil() as usize, height.ceil() as usize)
+    }
+
+    /// Returns the rectangle the whole image is drawn into when placed within `bounds` on a canvas
+    /// with `scale` pixels per point.
+    fn destination(&self, bounds: RectF, scale: f64) -> RectF {
+        let (width, height) = self.intrinsic_size(scale);
+        let (width, height) = match self.props.fit {
+            Fit::Stretch => return bounds,
+            Fit::Center => (width, height),
+            Fit::Contain | Fit::Cover => {
+                let x_scale = bounds.width / width;
+                let y_scale = bounds.height / height;
+                let scale = if self.props.fit == Fit::Contain {
+                    x_scale.min(y_scale)
+                } else {
+                    x_scale.max(y_scale)
+                };
+
+                (width * scale, height * scale)
+            }
+        };
+        let center = bounds.center();
+
+        RectF::new(
+            center.x - width / 2.0,
+            center.y - height / 2.0,
+            width,
+            height,
+        )
+    }
+}
+
+impl Drawable for Image {
+    /// The intrinsic size of the image at one pixel per point, rounded up to whole points.
+    fn content_size(&self, _bounds: Bounds) -> Size {
+        self.size(1.0)
+    }
+
+    /// The intrinsic size of the image at the scale of the constraints, rounded up to whole
+    /// points.
+    fn measure(&self, _position: Point, constraints: Constraints) -> Size {
+        constraints.constrain(self.size(constraints.scale))
+    }
+
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> UiResult<()> {
+        let bounds: RectF = bounds.into();
+        if self.bitmap.width() == 0 || self.bitmap.height() == 0 {
+            return Ok(());
+        }
+
+        let destination = self.destination(bounds, canvas.scale());
+        let visible = destination.intersect(bounds);
+
+        canvas.save();
+        match self.props.corner_radius {
+            Some(radius) if !radius.is_zero() => {
+                canvas.clip_path(&Path::rounded_rect(visible, radius.radii()))
+            }
+            _ if visible != destination => canvas.clip_rect(visible),
+            _ => {}
+        }
+        canvas.draw_image(&self.bitmap, destination);
+        canvas.restore();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        block::{self, Block},
+        canvas::{
+            raster::RasterCanvas,
+            recording::{DisplayItem, RecordingCanvas},
+        },
+        layout::{self, Direction, Layout},
+        snapshot::{assert_snapshot, reference_path},
+    };
+
+    use super::*;
+
+    /// A 4x2 pixel image, red on the left and blue on the right.
+    fn two_tone() -> Bitmap {
+        let data = (0..8)
+            .flat_map(|i| match i % 4 {
+                0 | 1 => [255, 0, 0, 255],
+                _ => [0, 0, 255, 255],
+            })
+            .collect();
+
+        Bitmap::from_rgba(4, 2, data).unwrap()
+    }
+
+    fn destination(fit: Fit, bounds: Bounds) -> (RectF, Option<DisplayItem>) {
+        let image = Image::new(
+            two_tone(),
+            Props {
+                fit,
+                ..Default::default()
+            },
+        );
+        let list = RecordingCanvas::record(&image, bounds).unwrap();
+        let clip = match &list.items()[1] {
+            DisplayItem::DrawImage { .. } => None,
+            clip => Some(clip.clone()),
+        };
+        let rect = list
+            .into_iter()
+            .find_map(|item| match item {
+                DisplayItem::DrawImage { rect, .. } => Some(rect),
+                _ => None,
+            })
+            .unwrap();
+
+        (rect, clip)
+    }
+
+    #[test]
+    fn fit_modes_place_image() {
+        let bounds = Bounds::new(0, 0, 8, 8);
+
+        assert_eq!(
+            destination(Fit::Contain, bounds),
+            (RectF::new(0.0, 2.0, 8.0, 4.0), None)
+        );
+        assert_eq!(
+            destination(Fit::Cover, bounds),
+            (
+                RectF::new(-4.0, 0.0, 16.0, 8.0),
+                Some(DisplayItem::ClipRect(RectF::new(0.0, 0.0, 8.0, 8.0)))
+            )
+        );
+        assert_eq!(
+            destination(Fit::Stretch, bounds),
+            (RectF::new(0.0, 0.0, 8.0, 8.0), None)
+        );
+        assert_eq!(
+            destination(Fit::Center, bounds),
+            (RectF::new(2.0, 3.0, 4.0, 2.0), None)
+        );
+    }
+
+    #[test]
+    fn size_follows_canvas_scale() {
+        let image = Image::new(
+            two_tone(),
+            Props {
+                fit: Fit::Center,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(image.intrinsic_size(2.0), (2.0, 1.0));
+        assert_eq!(
+            image.content_size(Bounds::new(0, 0, 100, 100)),
+            Size::new(4, 2)
+        );
+        let constraints = Constraints::loose(Size::new(100, 100));
+        assert_eq!(
+            image.measure(Point::origin(), constraints.with_scale(2.0)),
+            Size::new(2, 1)
+        );
+
+        // At twice the resolution the image takes up half as many points, with every image pixel
+        // covering exactly one device pixel.
+        let swatch = Block::new(
+            (),
+            block::Props {
+                background_color: Some(Color::WHITE.into()),
+                width: Some(1),
+                height: Some(1),
+                ..Default::default()
+            },
+        );
+        let row = Layout::with_children(
+            vec![image.boxed(), swatch.boxed()],
+            layout::Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+        let bitmap = RasterCanvas::render_with_scale(&row, Bounds::new(0, 0, 3, 1), 2.0)
+            .unwrap()
+            .to_bitmap();
+        assert_eq!(bitmap.pixel(0, 0), Some([255, 0, 0, 255]));
+        assert_eq!(bitmap.pixel(1, 1), Some([255, 0, 0, 255]));
+        assert_eq!(bitmap.pixel(2, 0), Some([0, 0, 255, 255]));
+        assert_eq!(bitmap.pixel(3, 1), Some([0, 0, 255, 255]));
+        assert_eq!(bitmap.pixel(4, 0), Some([255, 255, 255, 255]));
+    }
+
+    #[test]
+    fn image_fits_and_tints() {
+        let bitmap = Arc::new(two_tone());
+        let tile = |fit, corner_radius, tint| {
+            let image = Image::new(
+                bitmap.clone(),
+                Props {
+                    fit,
+                    corner_radius,
+                    tint,
+                },
+            );
+
+            Block::new(
+                image,
+                block::Props {
+                    width: Some(16),
+                    height: Some(16),
+                    ..Default::default()
+                },
+            )
+        };
+        let row = Layout::with_children(
+            vec![
+                tile(Fit::Contain, None, None),
+                tile(Fit::Cover, Some(CornerRadius::uni(6)), None),
+                tile(Fit::Stretch, None, Some(Color::rgb(1.0, 0.8, 0.0))),
+                tile(Fit::Center, None, None),
+            ],
+            layout::Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(&row, Bounds::new(0, 0, 64, 16), reference_path("image_fit"));
+    }
+}
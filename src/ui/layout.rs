@@ -1,10 +1,30 @@
-use crate::ui::geometry::{Bounds, Point, Size};
+use std::cell::RefCell;
 
-use super::Drawable;
+use crate::ui::geometry::{Bounds, Constraints, Point, Size};
 
+use super::{canvas::Canvas, AnyDrawable, Drawable};
+
+/// Lays out children one after the other along a row or column, following the flexbox model.
+///
+/// Each child starts out at its [`Flex::basis`] along the main axis. Space left over is handed
+/// out to children in proportion to their [`Flex::grow`] factors, while overflowing children give
+/// up space in proportion to their [`Flex::shrink`] factors. Whatever remains is distributed
+/// according to [`Props::justify_content`], and children are positioned across the layout
+/// according to [`Props::align_items`].
 pub struct Layout<Child> {
-    children: Vec<Child>,
+    children: Vec<(Child, Flex)>,
     props: Props,
+    /// The children as last arranged, which is reused to draw the layout at the size it was
+    /// measured at.
+    arrangement: RefCell<Option<Arrangement>>,
+}
+
+/// The bounds of the children of a [`Layout`] measured within `constraints`, relative to the
+/// position of the layout.
+struct Arrangement {
+    constraints: Constraints,
+    size: Size,
+    slots: Vec<Bounds>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,87 +36,1139 @@ pub enum Direction {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Props {
     pub direction: Direction,
+    /// How space left over along the main axis is distributed around the children.
+    pub justify_content: Justify,
+    /// How children are positioned across the main axis.
+    pub align_items: Align,
+    /// Space between consecutive children along the main axis.
+    pub gap: usize,
+}
+
+impl Default for Props {
+    fn default() -> Self {
+        Self {
+            direction: Direction::Row,
+            justify_content: Justify::default(),
+            align_items: Align::default(),
+            gap: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Justify {
+    /// Children are packed towards the start of the layout.
+    #[default]
+    Start,
+    /// Children are packed around the middle of the layout.
+    Center,
+    /// Children are packed towards the end of the layout.
+    End,
+    /// The first and last children touch the edges, with equal space between the others.
+    SpaceBetween,
+    /// Every child gets equal space on either side, so the edges get half as much space as the
+    /// gaps between children.
+    SpaceAround,
+    /// The edges and the gaps between children all get equal space.
+    SpaceEvenly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+    /// Children are placed at the top of a row, or at the left of a column.
+    Start,
+    /// Children are centered across the layout.
+    Center,
+    /// Children are placed at the bottom of a row, or at the right of a column.
+    End,
+    /// Children are stretched across the whole layout, as far as their own size allows.
+    #[default]
+    Stretch,
+    /// Children in a row are moved down so their first baselines line up, as reported by
+    /// [`Drawable::baseline`]. Children without a baseline line up by their bottom edge instead.
+    /// Children in a column are placed as with [`Align::Start`].
+    Baseline,
+}
+
+/// How a child of a [`Layout`] is sized along the main axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Flex {
+    /// Share of the space left over along the main axis given to the child, relative to the
+    /// other children. Children with a factor of `0.0` do not grow.
+    pub grow: f64,
+    /// Share of the overflow along the main axis taken away from the child, relative to the other
+    /// children and weighted by their bases. Children with a factor of `0.0` do not shrink.
+    pub shrink: f64,
+    /// Size of the child along the main axis before growing or shrinking, or [`None`] for its
+    /// content size.
+    pub basis: Option<usize>,
+}
+
+impl Default for Flex {
+    fn default() -> Self {
+        Self {
+            grow: 0.0,
+            shrink: 1.0,
+            basis: None,
+        }
+    }
+}
+
+impl Flex {
+    /// Creates a flex that grows by `grow` from the child's content size.
+    pub fn grow(grow: f64) -> Self {
+        Self {
+            grow,
+            ..Default::default()
+        }
+    }
+}
+
+impl Direction {
+    fn main(self, size: Size) -> usize {
+        match self {
+            Direction::Row => size.width,
+            Direction::Column => size.height,
+        }
+    }
+
+    fn cross(self, size: Size) -> usize {
+        match self {
+            Direction::Row => size.height,
+            Direction::Column => size.width,
+        }
+    }
+
+    fn size(self, main: usize, cross: usize) -> Size {
+        match self {
+            Direction::Row => Size::new(main, cross),
+            Direction::Column => Size::new(cross, main),
+        }
+    }
+
+    /// Returns the bounds at `main_offset` and `cross_offset` from `origin`, measuring `main`
+    /// along the main axis and `cross` across it.
+    fn bounds(
+        self,
+        origin: Point,
+        (main_offset, cross_offset): (usize, usize),
+        (main, cross): (usize, usize),
+    ) -> Bounds {
+        // Children overflowing far enough end up at the edge of the coordinate space, rather
+        // than wrapping around.
+        let (x, y) = match self {
+            Direction::Row => (main_offset, cross_offset),
+            Direction::Column => (cross_offset, main_offset),
+        };
+        let position = Point::new(origin.x.saturating_add(x), origin.y.saturating_add(y));
+
+        Bounds {
+            position,
+            size: self.size(main, cross),
+        }
+    }
 }
 
 impl<Child> Layout<Child> {
     pub fn empty(props: Props) -> Self {
+        Self::with_flex_children(Vec::new(), props)
+    }
+
+    /// Creates a layout of `children` sized by their content, which only shrink if they overflow.
+    pub fn with_children(children: Vec<Child>, props: Props) -> Self {
+        Self::with_flex_children(
+            children
+                .into_iter()
+                .map(|child| (child, Flex::default()))
+                .collect(),
+            props,
+        )
+    }
+
+    /// Creates a layout of `children`, each sized along the main axis by its [`Flex`].
+    pub fn with_flex_children(children: Vec<(Child, Flex)>, props: Props) -> Self {
         Self {
-            children: Vec::new(),
+            children,
             props,
+            arrangement: RefCell::new(None),
         }
     }
 
-    pub fn with_children(children: Vec<Child>, props: Props) -> Self {
-        Self { children, props }
+    /// Appends `child` to the end of the layout, sized by its content.
+    pub fn push(&mut self, child: Child) -> &mut Self {
+        self.push_flex(child, Flex::default())
     }
-}
 
-impl<Child: Drawable> Drawable for Layout<Child> {
-    fn content_size(&self, bounds: super::geometry::Bounds) -> super::geometry::Size {
-        let Bounds {
-            position: Point { x, y },
-            size: Size { width, height },
-        } = bounds;
+    /// Appends `child` to the end of the layout, sized along the main axis by `flex`.
+    pub fn push_flex(&mut self, child: Child, flex: Flex) -> &mut Self {
+        self.children.push((child, flex));
+        self.arrangement.take();
+        self
+    }
 
-        match self.props.direction {
-            Direction::Row => {
-                let mut used_width = 0;
+    pub fn props(&self) -> &Props {
+        &self.props
+    }
 
-                for child in &self.children {
-                    let child_bounds = Bounds::new(x + used_width, y, width - used_width, height);
-                    let child_size = child.content_size(child_bounds);
+    /// Whether the layout takes up the whole main axis, rather than only the space its children
+    /// need.
+    fn fills_main_axis(&self) -> bool {
+        self.props.justify_content != Justify::Start
+            || self.children.iter().any(|(_, flex)| flex.grow > 0.0)
+    }
 
-                    used_width += child_size.width;
-                }
+    /// Returns the sizes of the children along the main axis, grown or shrunk from `bases` to
+    /// make up for `free` space, which is negative if the children overflow.
+    fn flex_sizes(&self, bases: &[f64], free: f64) -> Vec<f64> {
+        let flexes = self.children.iter().map(|(_, flex)| flex);
 
-                Size::new(used_width, height)
+        if free > 0.0 {
+            let total: f64 = flexes.clone().map(|flex| flex.grow.max(0.0)).sum();
+            if total > 0.0 {
+                return bases
+                    .iter()
+                    .zip(flexes)
+                    .map(|(basis, flex)| basis + free * flex.grow.max(0.0) / total)
+                    .collect();
             }
-            Direction::Column => {
-                let mut used_height = 0;
+        } else if free < 0.0 {
+            // Children that would shrink past nothing stop there instead, and the overflow they
+            // cannot absorb is taken from their siblings, until it is absorbed or no child can
+            // shrink any further.
+            let target = bases.iter().sum::<f64>() + free;
+            let mut sizes = bases.to_vec();
+            let mut frozen: Vec<bool> = bases
+                .iter()
+                .zip(flexes.clone())
+                .map(|(basis, flex)| *basis <= 0.0 || flex.shrink <= 0.0)
+                .collect();
 
-                for child in &self.children {
-                    let child_bounds = Bounds::new(x, y + used_height, width, height - used_height);
-                    let child_size = child.content_size(child_bounds);
+            loop {
+                let mut overflow = -target;
+                let mut total = 0.0;
+                for ((size, basis), (flex, frozen)) in
+                    sizes.iter().zip(bases).zip(flexes.clone().zip(&frozen))
+                {
+                    if *frozen {
+                        overflow += size;
+                    } else {
+                        overflow += basis;
+                        total += basis * flex.shrink;
+                    }
+                }
+                if overflow <= 0.0 || total <= 0.0 {
+                    break;
+                }
 
-                    used_height += child_size.height;
+                let mut clamped = false;
+                for (((size, basis), flex), frozen) in sizes
+                    .iter_mut()
+                    .zip(bases)
+                    .zip(flexes.clone())
+                    .zip(&mut frozen)
+                    .filter(|(_, frozen)| !**frozen)
+                {
+                    *size = basis - overflow * basis * flex.shrink / total;
+                    if *size <= 0.0 {
+                        *size = 0.0;
+                        *frozen = true;
+                        clamped = true;
+                    }
+                }
+                if !clamped {
+                    break;
                 }
+            }
+
+            return sizes;
+        }
+
+        bases.to_vec()
+    }
+
+    /// Returns the space before the first child and the extra space between consecutive
+    /// children that distribute `free` space according to `justify_content`.
+    fn justify(&self, free: f64) -> (f64, f64) {
+        let count = self.children.len() as f64;
+
+        match self.props.justify_content {
+            Justify::Start => (0.0, 0.0),
+            Justify::Center => (free / 2.0, 0.0),
+            Justify::End => (free, 0.0),
+            Justify::SpaceBetween if count > 1.0 => (0.0, free / (count - 1.0)),
+            Justify::SpaceBetween => (0.0, 0.0),
+            Justify::SpaceAround => (free / count / 2.0, free / count),
+            Justify::SpaceEvenly => (free / (count + 1.0), free / (count + 1.0)),
+        }
+    }
+}
+
+impl Layout<AnyDrawable> {
+    /// Creates a layout of differently typed `children`, given as a tuple such as
+    /// `(title, Spacer::default(), clock)`, each sized by its content.
+    pub fn with_mixed_children(children: impl IntoChildren, props: Props) -> Self {
+        Self::with_children(children.into_children(), props)
+    }
+
+    /// Appends a [`Spacer`] that takes up the space left over along the main axis, pushing the
+    /// children after it towards the end of the layout.
+    pub fn push_spacer(&mut self) -> &mut Self {
+        self.push_flex(Spacer::default().boxed(), Flex::grow(1.0))
+    }
+}
+
+impl<Child> Extend<Child> for Layout<Child> {
+    /// Appends `children` to the end of the layout, each sized by its content.
+    fn extend<I: IntoIterator<Item = Child>>(&mut self, children: I) {
+        self.children
+            .extend(children.into_iter().map(|child| (child, Flex::default())));
+        self.arrangement.take();
+    }
+}
 
-                Size::new(width, used_height)
+/// Children of different types for [`Layout::with_mixed_children`], implemented for tuples of up
+/// to twelve drawables.
+pub trait IntoChildren {
+    fn into_children(self) -> Vec<AnyDrawable>;
+}
+
+macro_rules! impl_into_children {
+    ($($child:ident),+) => {
+        impl<$($child: Drawable + 'static),+> IntoChildren for ($($child,)+) {
+            #[allow(non_snake_case)]
+            fn into_children(self) -> Vec<AnyDrawable> {
+                let ($($child,)+) = self;
+                vec![$($child.boxed()),+]
             }
         }
+    };
+}
+
+impl_into_children!(A);
+impl_into_children!(A, B);
+impl_into_children!(A, B, C);
+impl_into_children!(A, B, C, D);
+impl_into_children!(A, B, C, D, E);
+impl_into_children!(A, B, C, D, E, F);
+impl_into_children!(A, B, C, D, E, F, G);
+impl_into_children!(A, B, C, D, E, F, G, H);
+impl_into_children!(A, B, C, D, E, F, G, H, I);
+impl_into_children!(A, B, C, D, E, F, G, H, I, J);
+impl_into_children!(A, B, C, D, E, F, G, H, I, J, K);
+impl_into_children!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// Empty space in a [`Layout`].
+///
+/// A spacer is `size` points long along either axis. Given a [`Flex::grow`] factor, as added by
+/// [`Layout::push_spacer`], it takes up the space left over between its neighbours instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Spacer {
+    pub size: usize,
+}
+
+impl Spacer {
+    pub fn new(size: usize) -> Self {
+        Self { size }
     }
+}
 
-    fn draw(&self, ctx: &super::Context, bounds: super::geometry::Bounds) -> super::UiResult<()> {
-        let Bounds {
-            position: Point { x, y },
-            size: Size { width, height },
-        } = bounds;
+impl Drawable for Spacer {
+    fn content_size(&self, bounds: Bounds) -> Size {
+        Size::new(
+            self.size.min(bounds.size.width),
+            self.size.min(bounds.size.height),
+        )
+    }
 
-        match self.props.direction {
-            Direction::Row => {
-                let mut used_width = 0;
+    fn draw(&self, _canvas: &mut dyn Canvas, _bounds: Bounds) -> super::UiResult<()> {
+        Ok(())
+    }
+}
 
-                for child in &self.children {
-                    let child_bounds = Bounds::new(x + used_width, y, width - used_width, height);
-                    let child_size = child.content_size(child_bounds);
-                    child.draw(ctx, child_bounds)?;
+impl<Child: Drawable> Layout<Child> {
+    /// Returns the bounds of each child within `bounds`, on a canvas with `scale` pixels per
+    /// point.
+    fn arrange(&self, bounds: Bounds, scale: f64) -> Vec<Bounds> {
+        if self.children.is_empty() {
+            return Vec::new();
+        }
+
+        let direction = self.props.direction;
+        let available = direction.main(bounds.size) as f64;
+        let cross_available = direction.cross(bounds.size);
+        let gaps = self.props.gap.saturating_mul(self.children.len() - 1) as f64;
+
+        let min_cross = match self.props.align_items {
+            Align::Stretch => cross_available,
+            _ => 0,
+        };
 
-                    used_width += child_size.width;
+        // Children are measured once, unless they have a basis. Only those whose size along the
+        // main axis then changes by flexing are measured again, as their size across the layout
+        // may depend on it.
+        let measured: Vec<Option<Size>> = self
+            .children
+            .iter()
+            .map(|(child, flex)| match flex.basis {
+                Some(_) => None,
+                None => Some(child.measure(
+                    bounds.position,
+                    Constraints::new(direction.size(0, min_cross), bounds.size).with_scale(scale),
+                )),
+            })
+            .collect();
+        let bases: Vec<f64> = self
+            .children
+            .iter()
+            .zip(&measured)
+            .map(|((_, flex), size)| match (flex.basis, size) {
+                (Some(basis), _) => basis as f64,
+                (None, size) => size.map_or(0, |size| direction.main(size)) as f64,
+            })
+            .collect();
+        let sizes = self.flex_sizes(&bases, available - gaps - bases.iter().sum::<f64>());
+        let free = (available - gaps - sizes.iter().sum::<f64>()).max(0.0);
+        let (mut position, spacing) = self.justify(free);
+
+        // Edges are rounded independently, so rounding errors do not add up along the layout.
+        let mut slots = Vec::with_capacity(sizes.len());
+        for size in sizes {
+            let start = position.round() as usize;
+            let end = (position + size).round() as usize;
+            slots.push((start, end - start));
+
+            position += size + self.props.gap as f64 + spacing;
+        }
+
+        // Children are measured across the layout at their final size along the main axis.
+        let cross_sizes: Vec<usize> = self
+            .children
+            .iter()
+            .zip(&slots)
+            .zip(&measured)
+            .map(|(((child, _), &(offset, main)), measured)| match measured {
+                Some(size) if direction.main(*size) == main => direction.cross(*size),
+                _ => {
+                    let slot =
+                        direction.bounds(bounds.position, (offset, 0), (main, cross_available));
+                    let constraints = Constraints::new(
+                        direction.size(main, min_cross),
+                        direction.size(main, cross_available),
+                    )
+                    .with_scale(scale);
+                    direction.cross(child.measure(slot.position, constraints))
                 }
+            })
+            .collect();
+        let baselines: Vec<f64> = match (self.props.align_items, direction) {
+            (Align::Baseline, Direction::Row) => self
+                .children
+                .iter()
+                .zip(slots.iter().zip(&cross_sizes))
+                .map(|((child, _), (&(offset, main), &cross))| {
+                    let slot = direction.bounds(bounds.position, (offset, 0), (main, cross));
+                    child.baseline(slot).unwrap_or(cross as f64)
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        let max_baseline = baselines.iter().copied().fold(0.0, f64::max);
+
+        slots
+            .into_iter()
+            .zip(cross_sizes)
+            .enumerate()
+            .map(|(i, ((offset, main), cross))| {
+                let cross_offset = match self.props.align_items {
+                    Align::Start | Align::Stretch => 0,
+                    Align::Center => cross_available.saturating_sub(cross) / 2,
+                    Align::End => cross_available.saturating_sub(cross),
+                    // Children are moved down no further than the bottom of the layout.
+                    Align::Baseline => baselines.get(i).map_or(0, |baseline| {
+                        ((max_baseline - baseline).round() as usize)
+                            .min(cross_available.saturating_sub(cross))
+                    }),
+                };
+                direction.bounds(bounds.position, (offset, cross_offset), (main, cross))
+            })
+            .collect()
+    }
+}
+
+impl<Child: Drawable> Drawable for Layout<Child> {
+    /// The size measured within `bounds`.
+    fn content_size(&self, bounds: Bounds) -> Size {
+        self.measure(bounds.position, Constraints::loose(bounds.size))
+    }
+
+    /// The extent of the children along the main axis, or all of the available space if children
+    /// grow or are justified, by all of the available space across it.
+    fn measure(&self, position: Point, constraints: Constraints) -> Size {
+        if let Some(arrangement) = &*self.arrangement.borrow() {
+            if arrangement.constraints == constraints {
+                return arrangement.size;
             }
-            Direction::Column => {
-                let mut used_height = 0;
+        }
 
-                for child in &self.children {
-                    let child_bounds = Bounds::new(x, y + used_height, width, height - used_height);
-                    let child_size = child.content_size(child_bounds);
-                    child.draw(ctx, child_bounds)?;
+        let direction = self.props.direction;
+        let bounds = Bounds {
+            position,
+            size: constraints.max,
+        };
+        let slots = self.arrange(bounds, constraints.scale);
 
-                    used_height += child_size.height;
+        let available = direction.main(bounds.size);
+        let main = if self.fills_main_axis() {
+            available
+        } else {
+            let end = |slot: &Bounds| match direction {
+                Direction::Row => (slot.position.x - position.x).saturating_add(slot.size.width),
+                Direction::Column => {
+                    (slot.position.y - position.y).saturating_add(slot.size.height)
                 }
-            }
+            };
+            slots.last().map_or(0, end).min(available)
+        };
+        let size = constraints.constrain(direction.size(main, direction.cross(bounds.size)));
+
+        // The children end up in the same place within the measured size as within the maximum,
+        // as any space beyond their extent is left empty.
+        let slots = slots
+            .into_iter()
+            .map(|slot| Bounds {
+                position: Point::new(slot.position.x - position.x, slot.position.y - position.y),
+                size: slot.size,
+            })
+            .collect();
+        self.arrangement.replace(Some(Arrangement {
+            constraints,
+            size,
+            slots,
+        }));
+
+        size
+    }
+
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> super::UiResult<()> {
+        let constraints = Constraints::tight(bounds.size).with_scale(canvas.scale());
+        let measured = self
+            .arrangement
+            .borrow()
+            .as_ref()
+            .map(|arrangement| (arrangement.size, arrangement.constraints.scale));
+        if measured != Some((bounds.size, constraints.scale)) {
+            self.measure(bounds.position, constraints);
+        }
+
+        let arrangement = self.arrangement.borrow();
+        let slots = arrangement
+            .iter()
+            .flat_map(|arrangement| &arrangement.slots);
+        for ((child, _), slot) in self.children.iter().zip(slots) {
+            let position = slot
+                .position
+                .translate(bounds.position.x, bounds.position.y);
+            child.draw(
+                canvas,
+                Bounds {
+                    position,
+                    size: slot.size,
+                },
+            )?;
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        block::{self, Block},
+        canvas::recording::{DisplayItem, RecordingCanvas},
+        color::Color,
+        font::Font,
+        geometry::{CornerRadius, Padding, RectF},
+        icons::battery::{self, BatteryIcon},
+        progress::ring::{self, RingGauge},
+        snapshot::{assert_snapshot, reference_path},
+        text::{self, Text},
+    };
+
+    use std::cell::Cell;
+
+    use super::*;
+
+    fn children() -> Vec<Block> {
+        [Color::RED, Color::GREEN, Color::BLUE]
+            .into_iter()
+            .map(|color| {
+                Block::new(
+                    (),
+                    block::Props {
+                        background_color: Some(color.into()),
+                        width: Some(10),
+                        height: Some(8),
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn row_places_children_side_by_side() {
+        let layout = Layout::with_children(
+            children(),
+            Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            layout.content_size(Bounds::new(0, 0, 40, 10)),
+            Size::new(30, 10)
+        );
+        assert_snapshot(
+            &layout,
+            Bounds::new(2, 1, 40, 10),
+            reference_path("layout_row"),
+        );
+    }
+
+    #[test]
+    fn column_stacks_children() {
+        let layout = Layout::with_children(
+            children(),
+            Props {
+                direction: Direction::Column,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            layout.content_size(Bounds::new(0, 0, 12, 30)),
+            Size::new(12, 24)
+        );
+        assert_snapshot(
+            &layout,
+            Bounds::new(1, 2, 12, 30),
+            reference_path("layout_column"),
+        );
+    }
+
+    fn slots<Child: Drawable>(
+        layout: &Layout<Child>,
+        bounds: Bounds,
+    ) -> Vec<(usize, usize, usize, usize)> {
+        layout
+            .arrange(bounds, 1.0)
+            .into_iter()
+            .map(|slot| {
+                (
+                    slot.position.x,
+                    slot.position.y,
+                    slot.size.width,
+                    slot.size.height,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn grow_shares_free_space() {
+        let mut flexes = [Flex::grow(1.0), Flex::grow(3.0), Flex::default()].into_iter();
+        let layout = Layout::with_flex_children(
+            children()
+                .into_iter()
+                .map(|child| (child, flexes.next().unwrap()))
+                .collect(),
+            Props::default(),
+        );
+        let bounds = Bounds::new(0, 0, 50, 10);
+
+        assert_eq!(layout.content_size(bounds), Size::new(50, 10));
+        assert_eq!(
+            slots(&layout, bounds),
+            [(0, 0, 15, 8), (15, 0, 25, 8), (40, 0, 10, 8)]
+        );
+    }
+
+    #[test]
+    fn shrink_is_weighted_by_basis() {
+        let flexes = [
+            Flex {
+                basis: Some(20),
+                ..Default::default()
+            },
+            Flex {
+                basis: Some(40),
+                ..Default::default()
+            },
+            Flex {
+                shrink: 0.0,
+                ..Default::default()
+            },
+        ];
+        let layout = Layout::with_flex_children(
+            children().into_iter().zip(flexes).collect(),
+            Props::default(),
+        );
+
+        // The children overflow by 30, which the first two give up in a ratio of 1 to 2.
+        assert_eq!(
+            slots(&layout, Bounds::new(0, 0, 40, 10)),
+            [(0, 0, 10, 8), (10, 0, 20, 8), (30, 0, 10, 8)]
+        );
+    }
+
+    #[test]
+    fn shrink_hands_on_unabsorbed_overflow() {
+        let flexes = [
+            Flex {
+                shrink: 4.0,
+                basis: Some(10),
+                ..Default::default()
+            },
+            Flex {
+                basis: Some(30),
+                ..Default::default()
+            },
+        ];
+        let layout = Layout::with_flex_children(
+            [Spacer::default(), Spacer::default()]
+                .into_iter()
+                .zip(flexes)
+                .collect(),
+            Props::default(),
+        );
+
+        // The first child would give up more than its basis, so the second takes the rest.
+        assert_eq!(
+            slots(&layout, Bounds::new(0, 0, 20, 10)),
+            [(0, 0, 0, 10), (0, 0, 20, 10)]
+        );
+    }
+
+    /// A spacer counting how often it is measured.
+    struct Counted<'a>(Spacer, &'a Cell<usize>);
+
+    impl Drawable for Counted<'_> {
+        fn content_size(&self, bounds: Bounds) -> Size {
+            self.1.set(self.1.get() + 1);
+            self.0.content_size(bounds)
+        }
+
+        fn draw(&self, _canvas: &mut dyn Canvas, _bounds: Bounds) -> crate::ui::UiResult<()> {
+            Ok(())
+        }
+    }
+
+    /// A drawable measuring at its size regardless of the constraints it is given.
+    struct Oversized(Size);
+
+    impl Drawable for Oversized {
+        fn content_size(&self, _bounds: Bounds) -> Size {
+            self.0
+        }
+
+        fn measure(&self, _position: Point, _constraints: Constraints) -> Size {
+            self.0
+        }
+
+        fn draw(&self, _canvas: &mut dyn Canvas, _bounds: Bounds) -> crate::ui::UiResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn children_are_measured_once() {
+        let count = Cell::new(0);
+        let layout = Layout::with_flex_children(
+            vec![
+                (Counted(Spacer::new(10), &count), Flex::default()),
+                (Counted(Spacer::new(10), &count), Flex::grow(1.0)),
+            ],
+            Props {
+                align_items: Align::Baseline,
+                ..Default::default()
+            },
+        );
+        layout.arrange(Bounds::new(0, 0, 40, 10), 1.0);
+
+        // Only the growing child is measured again at its final size.
+        assert_eq!(count.get(), 3);
+    }
+
+    #[test]
+    fn nested_layouts_are_arranged_once() {
+        fn column<Child: Drawable>(child: Child) -> Layout<Child> {
+            Layout::with_children(
+                vec![child],
+                Props {
+                    direction: Direction::Column,
+                    ..Default::default()
+                },
+            )
+        }
+
+        let count = Cell::new(0);
+        let layout = column(column(column(Counted(Spacer::new(10), &count))));
+        let bounds = Bounds::new(0, 0, 40, 10);
+
+        assert_eq!(
+            layout.measure(bounds.position, Constraints::loose(bounds.size)),
+            Size::new(40, 10)
+        );
+        RecordingCanvas::record(&layout, bounds).unwrap();
+
+        // Each layout reuses the arrangement its parent measured it with when drawn.
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn grown_and_stretched_blocks_fill_their_slots() {
+        let swatch = |color: Color| {
+            Block::new(
+                (),
+                block::Props {
+                    background_color: Some(color.into()),
+                    min_width: Some(8),
+                    ..Default::default()
+                },
+            )
+        };
+        let layout = Layout::with_flex_children(
+            vec![
+                (swatch(Color::RED), Flex::default()),
+                (swatch(Color::GREEN), Flex::grow(1.0)),
+                (swatch(Color::BLUE), Flex::default()),
+            ],
+            Props {
+                gap: 2,
+                ..Default::default()
+            },
+        );
+        let bounds = Bounds::new(0, 0, 40, 10);
+
+        // The blocks have no height of their own, and only a minimum width.
+        let fills: Vec<_> = RecordingCanvas::record(&layout, bounds)
+            .unwrap()
+            .into_iter()
+            .filter_map(|item| match item {
+                DisplayItem::FillRect(rect) => Some(rect),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            fills,
+            [
+                RectF::new(0.0, 0.0, 8.0, 10.0),
+                RectF::new(10.0, 0.0, 20.0, 10.0),
+                RectF::new(32.0, 0.0, 8.0, 10.0),
+            ]
+        );
+        assert_snapshot(&layout, bounds, reference_path("layout_stretch"));
+    }
+
+    #[test]
+    fn justify_content_distributes_free_space() {
+        let starts = |justify_content| {
+            let layout = Layout::with_children(
+                children(),
+                Props {
+                    justify_content,
+                    ..Default::default()
+                },
+            );
+            slots(&layout, Bounds::new(0, 0, 60, 10))
+                .into_iter()
+                .map(|(x, ..)| x)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(starts(Justify::Start), [0, 10, 20]);
+        assert_eq!(starts(Justify::Center), [15, 25, 35]);
+        assert_eq!(starts(Justify::End), [30, 40, 50]);
+        assert_eq!(starts(Justify::SpaceBetween), [0, 25, 50]);
+        assert_eq!(starts(Justify::SpaceAround), [5, 25, 45]);
+        assert_eq!(starts(Justify::SpaceEvenly), [8, 25, 43]);
+    }
+
+    #[test]
+    fn gap_separates_children() {
+        let layout = Layout::with_children(
+            children(),
+            Props {
+                direction: Direction::Column,
+                gap: 4,
+                ..Default::default()
+            },
+        );
+        let bounds = Bounds::new(0, 0, 12, 40);
+
+        assert_eq!(layout.content_size(bounds), Size::new(12, 32));
+        assert_eq!(
+            slots(&layout, bounds),
+            [(0, 0, 10, 8), (0, 12, 10, 8), (0, 24, 10, 8)]
+        );
+    }
+
+    #[test]
+    fn align_items_positions_across_layout() {
+        let offsets = |align_items| {
+            let layout = Layout::with_children(
+                children(),
+                Props {
+                    align_items,
+                    ..Default::default()
+                },
+            );
+            slots(&layout, Bounds::new(0, 0, 40, 20))[0]
+        };
+
+        assert_eq!(offsets(Align::Start), (0, 0, 10, 8));
+        assert_eq!(offsets(Align::Center), (0, 6, 10, 8));
+        assert_eq!(offsets(Align::End), (0, 12, 10, 8));
+        // Children keep a fixed size, rather than being stretched.
+        assert_eq!(offsets(Align::Stretch), (0, 0, 10, 8));
+    }
+
+    #[test]
+    fn baseline_aligns_text() {
+        let label = |size| {
+            Text::new(
+                "12:45",
+                text::Props {
+                    font: Font::default().with_size(size),
+                    ..Default::default()
+                },
+            )
+        };
+        let (small, large) = (label(10.0), label(20.0));
+        let drop =
+            large.baseline(Bounds::default()).unwrap() - small.baseline(Bounds::default()).unwrap();
+        let layout = Layout::with_children(
+            vec![small, large],
+            Props {
+                align_items: Align::Baseline,
+                ..Default::default()
+            },
+        );
+
+        let offsets: Vec<_> = layout
+            .arrange(Bounds::new(0, 0, 200, 40), 1.0)
+            .iter()
+            .map(|slot| slot.position.y)
+            .collect();
+        assert_eq!(offsets, [drop.round() as usize, 0]);
+    }
+
+    #[test]
+    fn baseline_stays_within_layout() {
+        let label = Text::new(
+            "12:45",
+            text::Props {
+                font: Font::default().with_size(20.0),
+                ..Default::default()
+            },
+        );
+        let line = Block::new(
+            (),
+            block::Props {
+                width: Some(10),
+                height: Some(2),
+                ..Default::default()
+            },
+        );
+        let layout = Layout::with_mixed_children(
+            (label, line),
+            Props {
+                align_items: Align::Baseline,
+                ..Default::default()
+            },
+        );
+
+        // The line has no baseline, so its bottom edge would line up with the text's baseline.
+        // The layout is too short for the text, so the line is kept at its bottom instead.
+        assert_eq!(slots(&layout, Bounds::new(0, 0, 200, 10))[1].1, 8);
+    }
+
+    #[test]
+    fn bar_with_centered_and_pushed_items() {
+        let label = |content| {
+            Text::new(
+                content,
+                text::Props {
+                    fill: Color::WHITE.into(),
+                    ..Default::default()
+                },
+            )
+        };
+        let row = Layout::with_children(
+            vec![label("Desktop 1"), label("Mail"), label("12:45")],
+            Props {
+                justify_content: Justify::SpaceBetween,
+                align_items: Align::Center,
+                ..Default::default()
+            },
+        );
+        let bar = Block::new(
+            row,
+            block::Props {
+                background_color: Some(Color::rgb(0.12, 0.12, 0.14).into()),
+                width: Some(240),
+                height: Some(40),
+                padding: Some(Padding::new(8, 8, 0, 0)),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &bar,
+            Bounds::new(0, 0, 240, 40),
+            reference_path("layout_flex"),
+        );
+    }
+
+    #[test]
+    fn mixed_children_keep_their_order() {
+        let [red, green, blue]: [Block; 3] = children().try_into().ok().unwrap();
+        let mut layout = Layout::with_mixed_children((red, Spacer::new(5), ()), Props::default());
+        layout.push(green.boxed());
+        layout.extend([Spacer::new(3).boxed(), blue.boxed()]);
+
+        let widths: Vec<_> = slots(&layout, Bounds::new(0, 0, 100, 10))
+            .into_iter()
+            .map(|(_, _, width, _)| width)
+            .collect();
+        assert_eq!(widths, [10, 5, 0, 10, 3, 10]);
+    }
+
+    #[test]
+    fn spacer_pushes_children_apart() {
+        let [red, green, blue]: [Block; 3] = children().try_into().ok().unwrap();
+        let mut layout = Layout::empty(Props {
+            gap: 2,
+            ..Default::default()
+        });
+        layout
+            .push(red.boxed())
+            .push_spacer()
+            .push(green.boxed())
+            .push(blue.boxed());
+
+        assert_eq!(
+            slots(&layout, Bounds::new(0, 0, 60, 10)),
+            [
+                (0, 0, 10, 8),
+                (12, 0, 24, 10),
+                (38, 0, 10, 8),
+                (50, 0, 10, 8)
+            ]
+        );
+    }
+
+    #[test]
+    fn bar_with_mixed_items() {
+        let label = |content| {
+            Text::new(
+                content,
+                text::Props {
+                    fill: Color::WHITE.into(),
+                    ..Default::default()
+                },
+            )
+        };
+        let workspace = Block::new(
+            label("Code"),
+            block::Props {
+                background_color: Some(Color::rgb(0.3, 0.7, 1.0).with_alpha(0.4).into()),
+                corner_radius: Some(CornerRadius::uni(4)),
+                padding: Some(Padding::new(6, 6, 2, 2)),
+                width: Some(44),
+                height: Some(20),
+                ..Default::default()
+            },
+        );
+        let gauge = Block::new(
+            RingGauge::new(0.4, ring::Props::default()),
+            block::Props {
+                width: Some(16),
+                height: Some(16),
+                ..Default::default()
+            },
+        );
+        let battery = Block::new(
+            BatteryIcon::new(battery::Status::new(0.7), battery::Props::default()),
+            block::Props {
+                width: Some(25),
+                height: Some(12),
+                ..Default::default()
+            },
+        );
+
+        let mut row = Layout::with_mixed_children(
+            (workspace, label("Terminal")),
+            Props {
+                align_items: Align::Center,
+                gap: 8,
+                ..Default::default()
+            },
+        );
+        row.push_spacer()
+            .extend([gauge.boxed(), battery.boxed(), label("12:45").boxed()]);
+        let bar = Block::new(
+            row,
+            block::Props {
+                background_color: Some(Color::rgb(0.12, 0.12, 0.14).into()),
+                width: Some(240),
+                height: Some(28),
+                padding: Some(Padding::new(4, 8, 0, 0)),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &bar,
+            Bounds::new(0, 0, 240, 28),
+            reference_path("layout_mixed"),
+        );
+    }
+
+    #[test]
+    fn overflowing_children_do_not_panic() {
+        let rigid = Flex {
+            shrink: 0.0,
+            ..Default::default()
+        };
+        let layout = Layout::with_flex_children(
+            children().into_iter().map(|child| (child, rigid)).collect(),
+            Props {
+                gap: usize::MAX / 2,
+                ..Default::default()
+            },
+        );
+        let bounds = Bounds::new(4, 0, 15, 10);
+
+        assert_eq!(layout.content_size(bounds), Size::new(15, 10));
+        let slots = slots(&layout, bounds);
+        assert_eq!(slots[0], (4, 0, 10, 8));
+        assert!(slots[1..].iter().all(|&(x, ..)| x > usize::MAX / 2));
+        RecordingCanvas::record(&layout, bounds).unwrap();
+
+        for align_items in [Align::Center, Align::End] {
+            let layout = Layout::with_children(
+                vec![Oversized(Size::new(10, 20))],
+                Props {
+                    align_items,
+                    ..Default::default()
+                },
+            );
+
+            assert_eq!(layout.arrange(bounds, 1.0), vec![Bounds::new(4, 0, 10, 20)]);
+            RecordingCanvas::record(&layout, bounds).unwrap();
+        }
+    }
+}
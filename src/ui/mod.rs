@@ -1,29 +1,115 @@
 use thiserror::Error;
 
-use self::geometry::{Bounds, Size};
+use self::{
+    canvas::Canvas,
+    geometry::{Bounds, Constraints, Point, Size},
+};
 
+#[cfg(target_os = "macos")]
 pub use crate::ffi::CGError;
 
+#[cfg(target_os = "macos")]
 pub mod app;
+pub mod bar_layout;
 pub mod block;
+pub mod canvas;
 pub mod color;
+pub mod font;
 pub mod geometry;
+pub mod graph;
+pub mod icons;
+pub mod image;
 pub mod layout;
+pub mod opacity;
+pub mod paint;
+pub mod progress;
+pub mod shape;
+pub mod snapshot;
+pub mod svg;
+pub mod text;
+#[cfg(target_os = "macos")]
 pub mod window;
 
-type Context = core_graphics::context::CGContext;
-
 pub type UiResult<T> = Result<T, Error>;
 
+/// A drawable of any type, for putting differently typed drawables side by side in a
+/// [`Layout`](layout::Layout).
+pub type AnyDrawable = Box<dyn Drawable>;
+
 #[derive(Debug, Error, Clone, PartialEq, Eq)]
 pub enum Error {
+    #[cfg(target_os = "macos")]
     #[error("core graphics internal error: {0}")]
     CgError(#[from] CGError),
+
+    #[error("cannot create a {0}x{1} drawing surface")]
+    InvalidSurfaceSize(u32, u32),
+
+    #[error("failed to encode image: {0}")]
+    ImageEncoding(String),
+
+    #[error("failed to decode image: {0}")]
+    ImageDecoding(String),
+
+    #[error("no font found for family `{0}`")]
+    FontNotFound(String),
+
+    #[error("failed to load font: {0}")]
+    InvalidFont(String),
+
+    #[error("failed to parse SVG: {0}")]
+    InvalidSvg(String),
 }
 
 pub trait Drawable {
     fn content_size(&self, bounds: Bounds) -> Size;
-    fn draw(&self, ctx: &Context, bounds: Bounds) -> UiResult<()>;
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> UiResult<()>;
+
+    /// Returns the size of the drawable at `position` within `constraints`.
+    ///
+    /// By default, this is the [`Drawable::content_size`] within the largest size allowed,
+    /// brought up to the minimum size if it is smaller. Containers override it to pass
+    /// constraints on to their children, and then draw each child into bounds at least as large
+    /// as it measured.
+    fn measure(&self, position: Point, constraints: Constraints) -> Size {
+        constraints.constrain(self.content_size(Bounds {
+            position,
+            size: constraints.max,
+        }))
+    }
+
+    /// Returns the distance from the top of `bounds` to the baseline of the first line of text
+    /// drawn into them, or [`None`] if the drawable has no text. Used to line up text across
+    /// siblings with [`Align::Baseline`](layout::Align::Baseline).
+    fn baseline(&self, _bounds: Bounds) -> Option<f64> {
+        None
+    }
+
+    /// Erases the type of the drawable, so it can be mixed with drawables of other types.
+    fn boxed(self) -> AnyDrawable
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(self)
+    }
+}
+
+impl<T: Drawable + ?Sized> Drawable for Box<T> {
+    fn content_size(&self, bounds: Bounds) -> Size {
+        (**self).content_size(bounds)
+    }
+
+    fn measure(&self, position: Point, constraints: Constraints) -> Size {
+        (**self).measure(position, constraints)
+    }
+
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> UiResult<()> {
+        (**self).draw(canvas, bounds)
+    }
+
+    fn baseline(&self, bounds: Bounds) -> Option<f64> {
+        (**self).baseline(bounds)
+    }
 }
 
 impl Drawable for () {
@@ -31,7 +117,7 @@ impl Drawable for () {
         Size::new(0, 0)
     }
 
-    fn draw(&self, _ctx: &Context, _bounds: Bounds) -> UiResult<()> {
+    fn draw(&self, _canvas: &mut dyn Canvas, _bounds: Bounds) -> UiResult<()> {
         Ok(())
     }
 }
@@ -0,0 +1,200 @@
+use super::{
+    canvas::Canvas,
+    geometry::{Bounds, Constraints, Point, Size},
+    Drawable, UiResult,
+};
+
+/// Draws its child at a reduced opacity, such as for fading a section of the bar in or out.
+///
+/// The child is rendered into a transparency layer that is composited as a whole, so the parts
+/// of the child that overlap (such as a block's background and the text on top of it) do not show
+/// through each other the way they would if every part were drawn translucently on its own.
+pub struct Opacity<Child> {
+    child: Child,
+    props: Props,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Props {
+    /// Opacity of the child, from `0.0` (invisible) to `1.0` (fully opaque).
+    pub opacity: f64,
+}
+
+impl Default for Props {
+    fn default() -> Self {
+        Self { opacity: 1.0 }
+    }
+}
+
+impl<Child: Drawable> Opacity<Child> {
+    pub fn new(child: Child, props: Props) -> Self {
+        Self { child, props }
+    }
+
+    pub fn props(&self) -> &Props {
+        &self.props
+    }
+
+    pub fn set_opacity(&mut self, opacity: f64) {
+        self.props.opacity = opacity;
+    }
+}
+
+impl<Child: Drawable> Drawable for Opacity<Child> {
+    /// The size of the child, which is laid out regardless of its opacity.
+    fn content_size(&self, bounds: Bounds) -> Size {
+        self.child.content_size(bounds)
+    }
+
+    fn measure(&self, position: Point, constraints: Constraints) -> Size {
+        self.child.measure(position, constraints)
+    }
+
+    fn baseline(&self, bounds: Bounds) -> Option<f64> {
+        self.child.baseline(bounds)
+    }
+
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> UiResult<()> {
+        let opacity = self.props.opacity;
+        if opacity >= 1.0 {
+            return self.child.draw(canvas, bounds);
+        }
+        if opacity.is_nan() || opacity <= 0.0 {
+            return Ok(());
+        }
+
+        canvas.begin_layer(opacity);
+        let result = self.child.draw(canvas, bounds);
+        canvas.end_layer();
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        block::{self, Block},
+        canvas::{
+            raster::RasterCanvas,
+            recording::{DisplayItem, RecordingCanvas},
+        },
+        color::Color,
+        geometry::{CornerRadius, Padding, RectF},
+        layout::{self, Direction, Layout},
+        snapshot::{assert_snapshot, reference_path},
+    };
+
+    use super::*;
+
+    fn swatch(color: Color) -> Block {
+        Block::new(
+            (),
+            block::Props {
+                width: Some(4),
+                height: Some(2),
+                background_color: Some(color.into()),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn wraps_child_in_layer() {
+        let bounds = Bounds::new(0, 0, 10, 10);
+        let faded = Opacity::new(swatch(Color::RED), Props { opacity: 0.25 });
+        assert_eq!(faded.content_size(bounds), Size::new(4, 2));
+
+        let list = RecordingCanvas::record(&faded, bounds).unwrap();
+        let items = list.items();
+        assert_eq!(
+            items.first(),
+            Some(&DisplayItem::BeginLayer { alpha: 0.25 })
+        );
+        assert_eq!(items.last(), Some(&DisplayItem::EndLayer));
+        assert!(items.contains(&DisplayItem::FillRect(RectF::new(0.0, 0.0, 4.0, 2.0))));
+
+        let opaque = Opacity::new(swatch(Color::RED), Props::default());
+        let list = RecordingCanvas::record(&opaque, bounds).unwrap();
+        assert!(!list.items().contains(&DisplayItem::EndLayer));
+
+        let hidden = Opacity::new(swatch(Color::RED), Props { opacity: 0.0 });
+        assert!(RecordingCanvas::record(&hidden, bounds).unwrap().is_empty());
+    }
+
+    #[test]
+    fn overlapping_children_fade_together() {
+        let faded = Opacity::new(
+            Block::new(
+                swatch(Color::BLUE),
+                block::Props {
+                    background_color: Some(Color::BLUE.into()),
+                    ..Default::default()
+                },
+            ),
+            Props { opacity: 0.5 },
+        );
+        let bitmap = RasterCanvas::render(&faded, Bounds::new(0, 0, 4, 2))
+            .unwrap()
+            .to_bitmap();
+
+        // The child is drawn on top of an identical parent, but no more opaque than either.
+        let swatch = RasterCanvas::render(
+            &Opacity::new(swatch(Color::BLUE), Props { opacity: 0.5 }),
+            Bounds::new(0, 0, 4, 2),
+        )
+        .unwrap()
+        .to_bitmap();
+        assert_eq!(bitmap.pixel(1, 1), swatch.pixel(1, 1));
+        assert_eq!(bitmap.pixel(1, 1).map(|pixel| pixel[3]), Some(128));
+    }
+
+    #[test]
+    fn faded_blocks() {
+        let pill = |opacity| {
+            Opacity::new(
+                Block::new(
+                    Block::new(
+                        (),
+                        block::Props {
+                            width: Some(16),
+                            height: Some(16),
+                            background_color: Some(Color::WHITE.into()),
+                            corner_radius: Some(CornerRadius::uni(4)),
+                            ..Default::default()
+                        },
+                    ),
+                    block::Props {
+                        width: Some(24),
+                        height: Some(24),
+                        padding: Some(Padding::uni(4)),
+                        background_color: Some(Color::rgb(0.3, 0.7, 1.0).into()),
+                        corner_radius: Some(CornerRadius::uni(6)),
+                        ..Default::default()
+                    },
+                ),
+                Props { opacity },
+            )
+        };
+        let row = Layout::with_children(
+            vec![pill(1.0), pill(0.75), pill(0.5), pill(0.25)],
+            layout::Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+        let canvas = Block::new(
+            row,
+            block::Props {
+                background_color: Some(Color::rgb(0.12, 0.12, 0.14).into()),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &canvas,
+            Bounds::new(0, 0, 96, 24),
+            reference_path("opacity"),
+        );
+    }
+}
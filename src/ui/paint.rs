@@ -0,0 +1,274 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    color::Color,
+    geometry::{PointF, RectF},
+};
+
+/// Describes how an area is filled: either with a single color or with a gradient.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Paint {
+    Solid(Color),
+    Gradient(Gradient),
+}
+
+impl Paint {
+    /// Returns the color at `point` when painting `area`.
+    pub fn color_at(&self, point: PointF, area: RectF) -> Color {
+        match self {
+            Paint::Solid(color) => *color,
+            Paint::Gradient(gradient) => gradient.color_at(gradient.offset_at(point, area)),
+        }
+    }
+}
+
+impl From<Color> for Paint {
+    fn from(value: Color) -> Self {
+        Paint::Solid(value)
+    }
+}
+
+impl From<Gradient> for Paint {
+    fn from(value: Gradient) -> Self {
+        Paint::Gradient(value)
+    }
+}
+
+/// A color at a given position along a [`Gradient`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GradientStop {
+    /// Position of the stop, from `0.0` at the start of the gradient to `1.0` at its end.
+    pub offset: f64,
+    pub color: Color,
+}
+
+impl GradientStop {
+    pub const fn new(offset: f64, color: Color) -> Self {
+        Self { offset, color }
+    }
+}
+
+/// The shape along which the colors of a [`Gradient`] progress.
+///
+/// Centers are relative to the painted area, with `(0.0, 0.0)` in its top-left corner and
+/// `(1.0, 1.0)` in its bottom-right corner. Angles are in degrees, measured clockwise from the top,
+/// as in CSS.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum GradientKind {
+    /// Colors progress along a line through the center of the area, pointing towards `angle`. The
+    /// line is just long enough for the corners of the area to be painted with the first and last
+    /// colors, so `90.0` goes from the left edge to the right edge.
+    Linear { angle: f64 },
+    /// Colors progress outwards in circles from `center`. A `radius` of `1.0` reaches the corner
+    /// of the area furthest from the center.
+    Radial { center: PointF, radius: f64 },
+    /// Colors progress clockwise around `center`, starting and ending at `angle`.
+    Conic { center: PointF, angle: f64 },
+}
+
+/// A smooth transition between multiple colors.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Gradient {
+    pub kind: GradientKind,
+    /// Colors of the gradient, in ascending order of offset. Areas before the first stop or after
+    /// the last stop are painted with its color.
+    pub stops: Vec<GradientStop>,
+}
+
+impl Gradient {
+    pub fn new(kind: GradientKind, stops: Vec<GradientStop>) -> Self {
+        Self { kind, stops }
+    }
+
+    /// Creates a linear gradient pointing towards `angle`.
+    pub fn linear(angle: f64, stops: Vec<GradientStop>) -> Self {
+        Self::new(GradientKind::Linear { angle }, stops)
+    }
+
+    /// Creates a radial gradient that fills the area from its center to its corners.
+    pub fn radial(stops: Vec<GradientStop>) -> Self {
+        let center = PointF::new(0.5, 0.5);
+        Self::new(
+            GradientKind::Radial {
+                center,
+                radius: 1.0,
+            },
+            stops,
+        )
+    }
+
+    /// Creates a conic gradient around the center of the area, starting at `angle`.
+    pub fn conic(angle: f64, stops: Vec<GradientStop>) -> Self {
+        let center = PointF::new(0.5, 0.5);
+        Self::new(GradientKind::Conic { center, angle }, stops)
+    }
+
+    /// Creates a gradient spreading `colors` evenly from start to end.
+    pub fn evenly_spaced(kind: GradientKind, colors: &[Color]) -> Self {
+        let last = colors.len().saturating_sub(1).max(1) as f64;
+        let stops = colors
+            .iter()
+            .enumerate()
+            .map(|(i, color)| GradientStop::new(i as f64 / last, *color))
+            .collect();
+
+        Self::new(kind, stops)
+    }
+
+    /// Returns the color at `offset` along the gradient, interpolating between the surrounding
+    /// stops.
+    pub fn color_at(&self, offset: f64) -> Color {
+        let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+            return Color::TRANSPARENT;
+        };
+        if offset <= first.offset {
+            return first.color;
+        }
+
+        for pair in self.stops.windows(2) {
+            let (start, end) = (pair[0], pair[1]);
+            if offset <= end.offset {
+                let span = end.offset - start.offset;
+                let t = if span > 0.0 {
+                    (offset - start.offset) / span
+                } else {
+                    1.0
+                };
+                let [r0, g0, b0, a0] = start.color.components();
+                let [r1, g1, b1, a1] = end.color.components();
+
+                return Color::rgba(
+                    r0 + (r1 - r0) * t,
+                    g0 + (g1 - g0) * t,
+                    b0 + (b1 - b0) * t,
+                    a0 + (a1 - a0) * t,
+                );
+            }
+        }
+
+        last.color
+    }
+
+    /// Returns the offset along the gradient at `point` when painting `area`. Offsets outside of
+    /// `0.0..=1.0` lie before the start or past the end of the gradient.
+    pub fn offset_at(&self, point: PointF, area: RectF) -> f64 {
+        match self.kind {
+            GradientKind::Linear { angle } => {
+                let (start, end) = linear_endpoints(angle, area);
+                let (dx, dy) = (end.x - start.x, end.y - start.y);
+                let length_squared = dx * dx + dy * dy;
+                if length_squared == 0.0 {
+                    return 0.0;
+                }
+
+                ((point.x - start.x) * dx + (point.y - start.y) * dy) / length_squared
+            }
+            GradientKind::Radial { center, radius } => {
+                let (center, radius) = radial_circle(center, radius, area);
+                if radius <= 0.0 {
+                    return 1.0;
+                }
+
+                (point.x - center.x).hypot(point.y - center.y) / radius
+            }
+            GradientKind::Conic { center, angle } => {
+                let center = resolve(center, area);
+                // Angle clockwise from the top, in turns.
+                let turns = (point.x - center.x).atan2(center.y - point.y) / std::f64::consts::TAU;
+
+                (turns - angle / 360.0).rem_euclid(1.0)
+            }
+        }
+    }
+}
+
+/// Returns the start and end points of a linear gradient pointing towards `angle` over `area`.
+pub(crate) fn linear_endpoints(angle: f64, area: RectF) -> (PointF, PointF) {
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let half_length = (area.width * sin.abs() + area.height * cos.abs()) / 2.0;
+    let center = area.center();
+    let (dx, dy) = (sin * half_length, -cos * half_length);
+
+    (
+        PointF::new(center.x - dx, center.y - dy),
+        PointF::new(center.x + dx, center.y + dy),
+    )
+}
+
+/// Returns the center and radius, in points, of a radial gradient over `area`.
+pub(crate) fn radial_circle(center: PointF, radius: f64, area: RectF) -> (PointF, f64) {
+    let center = resolve(center, area);
+    let furthest_x = (center.x - area.min_x()).max(area.max_x() - center.x);
+    let furthest_y = (center.y - area.min_y()).max(area.max_y() - center.y);
+
+    (center, furthest_x.hypot(furthest_y) * radius)
+}
+
+fn resolve(point: PointF, area: RectF) -> PointF {
+    PointF::new(
+        area.x + point.x * area.width,
+        area.y + point.y * area.height,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AREA: RectF = RectF {
+        x: 10.0,
+        y: 0.0,
+        width: 100.0,
+        height: 20.0,
+    };
+
+    #[test]
+    fn color_at_interpolates_between_stops() {
+        let gradient = Gradient::evenly_spaced(
+            GradientKind::Linear { angle: 90.0 },
+            &[Color::RED, Color::BLUE, Color::GREEN],
+        );
+
+        assert_eq!(gradient.color_at(-1.0), Color::RED);
+        assert_eq!(gradient.color_at(0.25), Color::rgb(0.5, 0.0, 0.5));
+        assert_eq!(gradient.color_at(0.5), Color::BLUE);
+        assert_eq!(gradient.color_at(2.0), Color::GREEN);
+        assert_eq!(Gradient::radial(vec![]).color_at(0.5), Color::TRANSPARENT);
+    }
+
+    fn assert_offset(gradient: &Gradient, x: f64, y: f64, expected: f64) {
+        let offset = gradient.offset_at(PointF::new(x, y), AREA);
+        assert!(
+            (offset - expected).abs() < 1e-9,
+            "offset at ({x}, {y}) is {offset}, expected {expected}"
+        );
+    }
+
+    #[test]
+    fn linear_gradient_spans_area() {
+        let horizontal = Gradient::linear(90.0, vec![]);
+        assert_offset(&horizontal, 10.0, 5.0, 0.0);
+        assert_offset(&horizontal, 60.0, 5.0, 0.5);
+
+        let upwards = Gradient::linear(0.0, vec![]);
+        assert_offset(&upwards, 30.0, 20.0, 0.0);
+        assert_offset(&upwards, 30.0, 0.0, 1.0);
+
+        // Diagonal gradients reach exactly into the corners.
+        let diagonal = Gradient::linear(135.0, vec![]);
+        assert_offset(&diagonal, 10.0, 0.0, 0.0);
+        assert_offset(&diagonal, 110.0, 20.0, 1.0);
+    }
+
+    #[test]
+    fn radial_and_conic_offsets() {
+        let radial = Gradient::radial(vec![]);
+        assert_offset(&radial, 60.0, 10.0, 0.0);
+        assert_offset(&radial, 110.0, 20.0, 1.0);
+
+        let conic = Gradient::conic(90.0, vec![]);
+        assert_offset(&conic, 65.0, 10.0, 0.0);
+        assert_offset(&conic, 60.0, 15.0, 0.25);
+        assert_offset(&conic, 60.0, 5.0, 0.75);
+    }
+}
@@ -0,0 +1,287 @@
+use crate::ui::{
+    canvas::{Canvas, Path},
+    color::Color,
+    geometry::{Bounds, RectF, Size},
+    paint::Paint,
+    Drawable, UiResult,
+};
+
+use super::{active_fill, clamp_value, set_fill, Threshold};
+
+/// A straight bar filled in proportion to a value from `0.0` to `1.0`.
+///
+/// The bar starts at the top-left corner of its bounds along its main axis, and is centered
+/// across it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressBar {
+    value: f64,
+    props: Props,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Props {
+    pub orientation: Orientation,
+    /// Length of the bar along its main axis, or [`None`] to take up all of the available space.
+    pub length: Option<usize>,
+    pub thickness: usize,
+    /// Paint of the unfilled part of the bar, if any.
+    pub track: Option<Paint>,
+    /// Paint of the filled part of the bar. Gradients span the whole track, so the part that is
+    /// revealed depends on the value.
+    pub fill: Paint,
+    /// Fills replacing `fill` as the value rises.
+    pub thresholds: Vec<Threshold>,
+    /// Whether the ends of the track and fill are rounded off.
+    pub rounded: bool,
+}
+
+impl Default for Props {
+    fn default() -> Self {
+        Self {
+            orientation: Orientation::default(),
+            length: None,
+            thickness: 4,
+            track: Some(Color::WHITE.with_alpha(0.2).into()),
+            fill: Color::WHITE.into(),
+            thresholds: Vec::new(),
+            rounded: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    /// Fills from left to right.
+    #[default]
+    Horizontal,
+    /// Fills from bottom to top.
+    Vertical,
+}
+
+impl ProgressBar {
+    pub fn new(value: f64, props: Props) -> Self {
+        Self { value, props }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn set_value(&mut self, value: f64) {
+        self.value = value;
+    }
+
+    pub fn props(&self) -> &Props {
+        &self.props
+    }
+
+    /// Returns the rectangle of the whole track within `bounds`.
+    fn track_rect(&self, bounds: Bounds) -> RectF {
+        let size = self.content_size(bounds);
+        let (x, y) = (bounds.position.x as f64, bounds.position.y as f64);
+        let (width, height) = (size.width as f64, size.height as f64);
+
+        match self.props.orientation {
+            Orientation::Horizontal => RectF::new(
+                x,
+                y + (bounds.size.height as f64 - height) / 2.0,
+                width,
+                height,
+            ),
+            Orientation::Vertical => RectF::new(
+                x + (bounds.size.width as f64 - width) / 2.0,
+                y,
+                width,
+                height,
+            ),
+        }
+    }
+
+    /// Returns the filled part of `track`.
+    fn fill_rect(&self, track: RectF) -> RectF {
+        let value = clamp_value(self.value);
+
+        match self.props.orientation {
+            Orientation::Horizontal => {
+                RectF::new(track.x, track.y, track.width * value, track.height)
+            }
+            Orientation::Vertical => {
+                let height = track.height * value;
+                RectF::new(track.x, track.max_y() - height, track.width, height)
+            }
+        }
+    }
+
+    fn shape(&self, rect: RectF) -> Path {
+        if self.props.rounded {
+            Path::rounded_rect(rect, [rect.width.min(rect.height) / 2.0; 4])
+        } else {
+            Path::rect(rect)
+        }
+    }
+}
+
+impl Drawable for ProgressBar {
+    fn content_size(&self, bounds: Bounds) -> Size {
+        let Size { width, height } = bounds.size;
+        let thickness = self.props.thickness;
+
+        match self.props.orientation {
+            Orientation::Horizontal => Size::new(
+                self.props.length.unwrap_or(width).min(width),
+                thickness.min(height),
+            ),
+            Orientation::Vertical => Size::new(
+                thickness.min(width),
+                self.props.length.unwrap_or(height).min(height),
+            ),
+        }
+    }
+
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> UiResult<()> {
+        let track = self.track_rect(bounds);
+        let filled = self.fill_rect(track);
+
+        canvas.save();
+        if let Some(paint) = &self.props.track {
+            set_fill(canvas, paint, track);
+            canvas.fill_path(&self.shape(track));
+        }
+        if filled.width > 0.0 && filled.height > 0.0 {
+            let value = clamp_value(self.value);
+            set_fill(
+                canvas,
+                active_fill(value, &self.props.fill, &self.props.thresholds),
+                track,
+            );
+            canvas.fill_path(&self.shape(filled));
+        }
+        canvas.restore();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        block::{self, Block},
+        canvas::recording::{DisplayItem, RecordingCanvas},
+        geometry::Padding,
+        layout::{self, Direction, Layout},
+        paint::{Gradient, GradientKind},
+        snapshot::{assert_snapshot, reference_path},
+    };
+
+    use super::*;
+
+    fn filled_bounds(bar: &ProgressBar, bounds: Bounds) -> Vec<RectF> {
+        RecordingCanvas::record(bar, bounds)
+            .unwrap()
+            .into_iter()
+            .filter_map(|item| match item {
+                DisplayItem::FillPath(path) => path.bounds(),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fills_in_proportion_to_value() {
+        let bounds = Bounds::new(10, 10, 100, 20);
+        let horizontal = ProgressBar::new(
+            0.25,
+            Props {
+                length: Some(80),
+                ..Default::default()
+            },
+        );
+        assert_eq!(horizontal.content_size(bounds), Size::new(80, 4));
+        assert_eq!(
+            filled_bounds(&horizontal, bounds),
+            [
+                RectF::new(10.0, 18.0, 80.0, 4.0),
+                RectF::new(10.0, 18.0, 20.0, 4.0)
+            ]
+        );
+
+        let vertical = ProgressBar::new(
+            2.0,
+            Props {
+                orientation: Orientation::Vertical,
+                track: None,
+                ..Default::default()
+            },
+        );
+        assert_eq!(vertical.content_size(bounds), Size::new(4, 20));
+        assert_eq!(
+            filled_bounds(&vertical, bounds),
+            [RectF::new(58.0, 10.0, 4.0, 20.0)]
+        );
+
+        let empty = ProgressBar::new(0.0, Props::default());
+        assert_eq!(filled_bounds(&empty, bounds).len(), 1);
+    }
+
+    #[test]
+    fn progress_bars() {
+        let bar = |value, props| {
+            Block::new(
+                ProgressBar::new(value, props),
+                block::Props {
+                    width: Some(64),
+                    height: Some(10),
+                    padding: Some(Padding::uni(2)),
+                    ..Default::default()
+                },
+            )
+        };
+        let horizontal = Props {
+            length: Some(60),
+            thickness: 6,
+            ..Default::default()
+        };
+
+        let column = Layout::with_children(
+            vec![
+                bar(0.6, horizontal.clone()),
+                bar(
+                    0.85,
+                    Props {
+                        fill: Gradient::evenly_spaced(
+                            GradientKind::Linear { angle: 90.0 },
+                            &[Color::rgb(0.2, 0.8, 0.4), Color::rgb(1.0, 0.3, 0.2)],
+                        )
+                        .into(),
+                        ..horizontal.clone()
+                    },
+                ),
+                bar(
+                    0.95,
+                    Props {
+                        thresholds: vec![Threshold::new(0.9, Color::rgb(1.0, 0.3, 0.2))],
+                        rounded: false,
+                        ..horizontal
+                    },
+                ),
+            ],
+            layout::Props {
+                direction: Direction::Column,
+                ..Default::default()
+            },
+        );
+        let canvas = Block::new(
+            column,
+            block::Props {
+                background_color: Some(Color::rgb(0.12, 0.12, 0.14).into()),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &canvas,
+            Bounds::new(0, 0, 64, 30),
+            reference_path("progress_bar"),
+        );
+    }
+}
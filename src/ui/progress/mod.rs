@@ -0,0 +1,73 @@
+//! Indicators showing a value between `0.0` and `1.0`, such as the volume or disk usage.
+
+use super::{canvas::Canvas, geometry::RectF, paint::Paint};
+
+pub mod bar;
+pub mod ring;
+
+/// Replaces the fill of an indicator once its value reaches `from`.
+///
+/// Thresholds apply in ascending order, so an indicator that should turn red when running low
+/// uses red as its fill and a threshold at the low mark for its normal color.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Threshold {
+    pub from: f64,
+    pub fill: Paint,
+}
+
+impl Threshold {
+    pub fn new(from: f64, fill: impl Into<Paint>) -> Self {
+        Self {
+            from,
+            fill: fill.into(),
+        }
+    }
+}
+
+/// Returns `value` clamped to `0.0..=1.0`, treating NaN as `0.0`.
+fn clamp_value(value: f64) -> f64 {
+    if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, 1.0)
+    }
+}
+
+/// Returns the fill of the highest threshold reached by `value`, or `fill` if none is reached.
+fn active_fill<'a>(value: f64, fill: &'a Paint, thresholds: &'a [Threshold]) -> &'a Paint {
+    thresholds
+        .iter()
+        .filter(|threshold| value >= threshold.from)
+        .max_by(|a, b| a.from.total_cmp(&b.from))
+        .map_or(fill, |threshold| &threshold.fill)
+}
+
+/// Sets `paint` as the fill of `canvas`, with gradients spanning `area`.
+fn set_fill(canvas: &mut dyn Canvas, paint: &Paint, area: RectF) {
+    match paint {
+        Paint::Solid(color) => canvas.set_fill_color(*color),
+        paint => canvas.set_fill_paint(paint, area),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::color::Color;
+
+    use super::*;
+
+    #[test]
+    fn highest_reached_threshold_wins() {
+        let fill = Paint::from(Color::RED);
+        let thresholds = [
+            Threshold::new(0.8, Color::BLUE),
+            Threshold::new(0.2, Color::GREEN),
+        ];
+
+        assert_eq!(active_fill(0.1, &fill, &thresholds), &fill);
+        assert_eq!(active_fill(0.2, &fill, &thresholds), &Color::GREEN.into());
+        assert_eq!(active_fill(0.9, &fill, &thresholds), &Color::BLUE.into());
+        assert_eq!(clamp_value(f64::NAN), 0.0);
+        assert_eq!(clamp_value(1.5), 1.0);
+    }
+}
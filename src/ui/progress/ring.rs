@@ -0,0 +1,267 @@
+use crate::ui::{
+    canvas::{Canvas, Path},
+    color::Color,
+    geometry::{Bounds, PointF, RectF, Size},
+    paint::Paint,
+    Drawable, UiResult,
+};
+
+use super::{active_fill, clamp_value, set_fill, Threshold};
+
+/// A circular gauge whose ring is filled clockwise in proportion to a value from `0.0` to `1.0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RingGauge {
+    value: f64,
+    props: Props,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Props {
+    /// Outer diameter of the ring, or [`None`] to be as large as fits into the bounds.
+    pub diameter: Option<usize>,
+    /// Width of the ring.
+    pub thickness: f64,
+    /// Angle the ring starts at, in degrees clockwise from the top.
+    pub start_angle: f64,
+    /// Angle spanned by a full ring, such as `270.0` for a gauge open at the bottom.
+    pub sweep_angle: f64,
+    /// Paint of the unfilled part of the ring, if any.
+    pub track: Option<Paint>,
+    /// Paint of the filled part of the ring. Gradients span the square around the ring, so a
+    /// conic gradient follows it around.
+    pub fill: Paint,
+    /// Fills replacing `fill` as the value rises.
+    pub thresholds: Vec<Threshold>,
+    /// Whether the ends of the track and fill are rounded off.
+    pub rounded: bool,
+}
+
+impl Default for Props {
+    fn default() -> Self {
+        Self {
+            diameter: None,
+            thickness: 3.0,
+            start_angle: 0.0,
+            sweep_angle: 360.0,
+            track: Some(Color::WHITE.with_alpha(0.2).into()),
+            fill: Color::WHITE.into(),
+            thresholds: Vec::new(),
+            rounded: true,
+        }
+    }
+}
+
+impl RingGauge {
+    pub fn new(value: f64, props: Props) -> Self {
+        Self { value, props }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn set_value(&mut self, value: f64) {
+        self.value = value;
+    }
+
+    pub fn props(&self) -> &Props {
+        &self.props
+    }
+
+    /// Returns the outline of the part of the ring inside `square` spanning `sweep_angle` degrees
+    /// from the start.
+    fn segment(&self, square: RectF, sweep_angle: f64) -> Path {
+        let center = square.center();
+        let outer = square.width / 2.0;
+        let thickness = self.props.thickness.clamp(0.0, outer);
+        let inner = outer - thickness;
+        let start = self.props.start_angle;
+        let end = start + sweep_angle;
+        // Centers of the rounded ends, halfway through the ring.
+        let cap_center = |angle: f64| {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            let radius = outer - thickness / 2.0;
+            PointF::new(center.x + radius * sin, center.y - radius * cos)
+        };
+
+        let mut path = Path::new();
+        path.arc(center, outer, start, sweep_angle);
+        if self.props.rounded {
+            path.arc(cap_center(end), thickness / 2.0, end, 180.0);
+        }
+        if inner > 0.0 {
+            path.arc(center, inner, end, -sweep_angle);
+        } else {
+            path.line_to(center.x, center.y);
+        }
+        if self.props.rounded {
+            path.arc(cap_center(start), thickness / 2.0, start + 180.0, 180.0);
+        }
+        path.close();
+
+        path
+    }
+}
+
+impl Drawable for RingGauge {
+    /// A square as large as the diameter, or as the bounds allow.
+    fn content_size(&self, bounds: Bounds) -> Size {
+        let Size { width, height } = bounds.size;
+        let diameter = self
+            .props
+            .diameter
+            .unwrap_or(usize::MAX)
+            .min(width)
+            .min(height);
+
+        Size::new(diameter, diameter)
+    }
+
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> UiResult<()> {
+        let size = self.content_size(bounds);
+        let square = RectF::new(
+            bounds.position.x as f64,
+            bounds.position.y as f64,
+            size.width as f64,
+            size.height as f64,
+        );
+        if square.width <= 0.0 || self.props.thickness <= 0.0 {
+            return Ok(());
+        }
+
+        canvas.save();
+        if let Some(paint) = &self.props.track {
+            set_fill(canvas, paint, square);
+            canvas.fill_path(&self.segment(square, self.props.sweep_angle));
+        }
+        let value = clamp_value(self.value);
+        if value > 0.0 {
+            set_fill(
+                canvas,
+                active_fill(value, &self.props.fill, &self.props.thresholds),
+                square,
+            );
+            canvas.fill_path(&self.segment(square, self.props.sweep_angle * value));
+        }
+        canvas.restore();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        block::{self, Block},
+        canvas::recording::{DisplayItem, RecordingCanvas},
+        geometry::Padding,
+        layout::{self, Direction, Layout},
+        paint::{Gradient, GradientKind},
+        snapshot::{assert_snapshot, reference_path},
+    };
+
+    use super::*;
+
+    #[test]
+    fn fills_clockwise_from_start() {
+        let gauge = RingGauge::new(
+            0.5,
+            Props {
+                diameter: Some(20),
+                thickness: 4.0,
+                track: None,
+                rounded: false,
+                ..Default::default()
+            },
+        );
+        let bounds = Bounds::new(0, 0, 100, 40);
+        assert_eq!(gauge.content_size(bounds), Size::new(20, 20));
+
+        // Half of the ring covers the right half of the square.
+        let fills: Vec<_> = RecordingCanvas::record(&gauge, bounds)
+            .unwrap()
+            .into_iter()
+            .filter_map(|item| match item {
+                DisplayItem::FillPath(path) => path.bounds(),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(fills.len(), 1);
+        let fill = fills[0];
+        assert!((fill.min_x() - 10.0).abs() < 1e-9, "{fill:?}");
+        assert!((fill.max_x() - 20.0).abs() < 0.5, "{fill:?}");
+        assert!((fill.height - 20.0).abs() < 1e-9, "{fill:?}");
+    }
+
+    #[test]
+    fn ring_gauges() {
+        let gauge = |value, props| {
+            Block::new(
+                RingGauge::new(value, props),
+                block::Props {
+                    width: Some(24),
+                    height: Some(24),
+                    padding: Some(Padding::uni(2)),
+                    ..Default::default()
+                },
+            )
+        };
+        let row = Layout::with_children(
+            vec![
+                gauge(0.4, Props::default()),
+                gauge(
+                    0.75,
+                    Props {
+                        start_angle: -135.0,
+                        sweep_angle: 270.0,
+                        fill: Gradient::evenly_spaced(
+                            GradientKind::Conic {
+                                center: PointF::new(0.5, 0.5),
+                                angle: -135.0,
+                            },
+                            &[Color::rgb(0.2, 0.8, 0.4), Color::rgb(1.0, 0.3, 0.2)],
+                        )
+                        .into(),
+                        ..Default::default()
+                    },
+                ),
+                gauge(
+                    0.95,
+                    Props {
+                        thickness: 5.0,
+                        rounded: false,
+                        thresholds: vec![Threshold::new(0.9, Color::rgb(1.0, 0.3, 0.2))],
+                        ..Default::default()
+                    },
+                ),
+                gauge(
+                    0.6,
+                    Props {
+                        thickness: 10.0,
+                        track: None,
+                        fill: Color::rgb(0.3, 0.7, 1.0).into(),
+                        rounded: false,
+                        ..Default::default()
+                    },
+                ),
+            ],
+            layout::Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+        let canvas = Block::new(
+            row,
+            block::Props {
+                background_color: Some(Color::rgb(0.12, 0.12, 0.14).into()),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &canvas,
+            Bounds::new(0, 0, 96, 24),
+            reference_path("ring_gauge"),
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use super::{
+    canvas::{Canvas, Path, StrokeStyle},
+    color::Color,
+    geometry::{Bounds, PointF, Size},
+    paint::Paint,
+    Drawable, UiResult,
+};
+
+/// A custom vector shape that fills and/or strokes a [`Path`].
+///
+/// The path is given in coordinates relative to the top-left corner of the shape's bounds. The
+/// shape is as large as needed to reach the right and bottom edges of the path, including the
+/// outer half of its stroke.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shape {
+    path: Path,
+    props: Props,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Props {
+    /// Paint the inside of the path is filled with. Gradients span the bounds of the path.
+    pub fill: Option<Paint>,
+    /// Line drawn along the path, on top of the fill.
+    pub stroke: Option<Stroke>,
+}
+
+/// The outline drawn along a [`Shape`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stroke {
+    pub color: Color,
+    pub style: StrokeStyle,
+}
+
+impl Stroke {
+    /// Creates a solid stroke of `width` with the default caps and joins.
+    pub fn new(width: f64, color: Color) -> Self {
+        Self {
+            color,
+            style: StrokeStyle {
+                width,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Shape {
+    pub fn new(path: Path, props: Props) -> Self {
+        Self { path, props }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn set_path(&mut self, path: Path) {
+        self.path = path;
+    }
+
+    pub fn props(&self) -> &Props {
+        &self.props
+    }
+}
+
+impl Drawable for Shape {
+    fn content_size(&self, bounds: Bounds) -> Size {
+        let Some(path_bounds) = self.path.bounds() else {
+            return Size::zero();
+        };
+        let outset = match &self.props.stroke {
+            Some(stroke) => stroke.style.width / 2.0,
+            None => 0.0,
+        };
+        let extent = |max: f64| (max + outset).ceil().max(0.0) as usize;
+
+        Size::new(
+            extent(path_bounds.max_x()).min(bounds.size.width),
+            extent(path_bounds.max_y()).min(bounds.size.height),
+        )
+    }
+
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> UiResult<()> {
+        let origin: PointF = bounds.position.into();
+        let path = self
+            .path
+            .map_points(|p| PointF::new(origin.x + p.x, origin.y + p.y));
+
+        canvas.save();
+        match &self.props.fill {
+            Some(Paint::Solid(color)) => {
+                canvas.set_fill_color(*color);
+                canvas.fill_path(&path);
+            }
+            Some(paint) => {
+                if let Some(area) = path.bounds() {
+                    canvas.set_fill_paint(paint, area);
+                    canvas.fill_path(&path);
+                }
+            }
+            None => {}
+        }
+        if let Some(stroke) = &self.props.stroke {
+            canvas.set_stroke_color(stroke.color);
+            canvas.set_stroke_style(&stroke.style);
+            canvas.stroke_path(&path);
+        }
+        canvas.restore();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        block::{self, Block},
+        canvas::{
+            recording::{DisplayItem, RecordingCanvas},
+            Dash, LineCap, LineJoin,
+        },
+        geometry::RectF,
+        layout::{self, Direction, Layout},
+        paint::Gradient,
+        snapshot::{assert_snapshot, reference_path},
+    };
+
+    use super::*;
+
+    fn zigzag() -> Path {
+        let mut path = Path::new();
+        path.move_to(4.0, 20.0)
+            .line_to(10.0, 6.0)
+            .line_to(16.0, 20.0)
+            .line_to(22.0, 6.0);
+
+        path
+    }
+
+    #[test]
+    fn content_size_includes_stroke() {
+        let bounds = Bounds::new(0, 0, 100, 100);
+        let filled = Shape::new(
+            Path::circle(PointF::new(8.0, 8.0), 6.0),
+            Props {
+                fill: Some(Color::RED.into()),
+                ..Default::default()
+            },
+        );
+        assert_eq!(filled.content_size(bounds), Size::new(14, 14));
+
+        let stroked = Shape::new(
+            zigzag(),
+            Props {
+                stroke: Some(Stroke::new(3.0, Color::BLUE)),
+                ..Default::default()
+            },
+        );
+        assert_eq!(stroked.content_size(bounds), Size::new(24, 22));
+        assert_eq!(
+            stroked.content_size(Bounds::new(0, 0, 10, 10)),
+            Size::new(10, 10)
+        );
+        assert_eq!(
+            Shape::new(Path::new(), Props::default()).content_size(bounds),
+            Size::zero()
+        );
+    }
+
+    #[test]
+    fn draws_relative_to_bounds() {
+        let stroke = Stroke::new(2.0, Color::BLUE);
+        let shape = Shape::new(
+            Path::rect(RectF::new(0.0, 0.0, 4.0, 4.0)),
+            Props {
+                fill: Some(Gradient::linear(90.0, vec![]).into()),
+                stroke: Some(stroke.clone()),
+            },
+        );
+        let list = RecordingCanvas::record(&shape, Bounds::new(10, 20, 50, 50)).unwrap();
+        let moved = Path::rect(RectF::new(10.0, 20.0, 4.0, 4.0));
+
+        assert_eq!(
+            list.items(),
+            [
+                DisplayItem::Save,
+                DisplayItem::SetFillPaint {
+                    paint: Gradient::linear(90.0, vec![]).into(),
+                    area: RectF::new(10.0, 20.0, 4.0, 4.0),
+                },
+                DisplayItem::FillPath(moved.clone()),
+                DisplayItem::SetStrokeColor(Color::BLUE),
+                DisplayItem::SetStrokeStyle(stroke.style),
+                DisplayItem::StrokePath(moved),
+                DisplayItem::Restore,
+            ]
+        );
+    }
+
+    #[test]
+    fn shapes_with_caps_joins_and_dashes() {
+        let stroked = |cap, join, dash| {
+            Shape::new(
+                zigzag(),
+                Props {
+                    stroke: Some(Stroke {
+                        color: Color::WHITE,
+                        style: StrokeStyle {
+                            width: 3.0,
+                            dash,
+                            cap,
+                            join,
+                            ..Default::default()
+                        },
+                    }),
+                    ..Default::default()
+                },
+            )
+        };
+        let mut pie = Path::new();
+        pie.move_to(13.0, 13.0)
+            .arc(PointF::new(13.0, 13.0), 10.0, 30.0, 300.0)
+            .close();
+
+        let row = Layout::with_children(
+            vec![
+                stroked(LineCap::Butt, LineJoin::Miter, None),
+                stroked(LineCap::Round, LineJoin::Round, None),
+                stroked(LineCap::Square, LineJoin::Bevel, None),
+                stroked(
+                    LineCap::Round,
+                    LineJoin::Round,
+                    Some(Dash::new(vec![0.0, 5.0], 0.0)),
+                ),
+                Shape::new(
+                    pie,
+                    Props {
+                        fill: Some(Color::rgb(1.0, 0.8, 0.0).into()),
+                        stroke: Some(Stroke::new(1.0, Color::WHITE)),
+                    },
+                ),
+            ],
+            layout::Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+        let canvas = Block::new(
+            row,
+            block::Props {
+                background_color: Some(Color::rgb(0.12, 0.12, 0.14).into()),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &canvas,
+            Bounds::new(0, 0, 128, 26),
+            reference_path("shapes"),
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! Golden-image snapshot testing for drawables.
+//!
+//! A drawable is rendered headlessly with a [`RasterCanvas`] and compared pixel by pixel against
+//! a reference PNG. When the `WUNDERBAR_UPDATE_SNAPSHOTS` environment variable is set, the
+//! rendered image is written as the new reference instead. A missing reference is an error unless
+//! updating, so a deleted or misnamed reference cannot silently turn into a passing test.
+//!
+//! On a mismatch, two images are written next to the reference: `<name>.actual.png` holding the
+//! rendered output, and `<name>.diff.png` highlighting every pixel that differs in red.
+
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+use tiny_skia::Pixmap;
+
+use super::{canvas::raster::RasterCanvas, geometry::Bounds, Drawable};
+
+/// Environment variable which, when set, causes references to be overwritten with the rendered
+/// output instead of being compared against.
+pub const UPDATE_ENV_VAR: &str = "WUNDERBAR_UPDATE_SNAPSHOTS";
+
+#[derive(Debug, Error)]
+pub enum SnapshotError {
+    #[error("failed to render drawable: {0}")]
+    Render(#[from] super::Error),
+
+    #[error("failed to read or write snapshot image: {0}")]
+    Image(String),
+
+    #[error(
+        "reference {} does not exist, set {UPDATE_ENV_VAR} to record it",
+        .0.display()
+    )]
+    MissingReference(PathBuf),
+
+    #[error("rendered size {actual:?} does not match reference size {expected:?}")]
+    SizeMismatch {
+        expected: (u32, u32),
+        actual: (u32, u32),
+    },
+
+    #[error("{mismatched_pixels} pixels differ from the reference, see {}", diff_path.display())]
+    Mismatch {
+        mismatched_pixels: usize,
+        diff_path: PathBuf,
+    },
+}
+
+/// The outcome of a successful [`Snapshot::check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotOutcome {
+    /// The rendered output matched the reference.
+    Matched,
+    /// An update was requested, so the reference was (re)written.
+    Recorded,
+}
+
+/// Compares the rendering of a drawable against a reference image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    reference: PathBuf,
+    tolerance: u8,
+    update: bool,
+}
+
+impl Snapshot {
+    /// Creates a snapshot comparison against the PNG at `reference`, with no tolerance.
+    pub fn new(reference: impl Into<PathBuf>) -> Self {
+        Self {
+            reference: reference.into(),
+            tolerance: 0,
+            update: std::env::var_os(UPDATE_ENV_VAR).is_some(),
+        }
+    }
+
+    /// Sets the largest difference allowed between any channel of a rendered pixel and the
+    /// corresponding reference pixel before the pixel is considered mismatched.
+    pub fn tolerance(mut self, tolerance: u8) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets whether the reference should be overwritten with the rendered output. Defaults to
+    /// whether [`UPDATE_ENV_VAR`] is set.
+    pub fn update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    /// Renders `drawable` at `bounds` and compares it against the reference image.
+    pub fn check(
+        &self,
+        drawable: &dyn Drawable,
+        bounds: Bounds,
+    ) -> Result<SnapshotOutcome, SnapshotError> {
+        let canvas = RasterCanvas::render(drawable, bounds)?;
+        let actual = canvas.encode_png()?;
+
+        if self.update {
+            if let Some(parent) = self.reference.parent() {
+                std::fs::create_dir_all(parent).map_err(image_error)?;
+            }
+            std::fs::write(&self.reference, actual).map_err(image_error)?;
+
+            return Ok(SnapshotOutcome::Recorded);
+        }
+        if !self.reference.exists() {
+            return Err(SnapshotError::MissingReference(self.reference.clone()));
+        }
+
+        let actual = Pixmap::decode_png(&actual).map_err(image_error)?;
+        let expected = Pixmap::load_png(&self.reference).map_err(image_error)?;
+
+        if (actual.width(), actual.height()) != (expected.width(), expected.height()) {
+            self.write_sibling("actual", &actual)?;
+
+            return Err(SnapshotError::SizeMismatch {
+                expected: (expected.width(), expected.height()),
+                actual: (actual.width(), actual.height()),
+            });
+        }
+
+        let mut diff = Pixmap::new(actual.width(), actual.height())
+            .expect("rendered image has a non-zero size");
+        let mut mismatched_pixels = 0;
+
+        for ((actual, expected), diff) in actual
+            .data()
+            .chunks_exact(4)
+            .zip(expected.data().chunks_exact(4))
+            .zip(diff.data_mut().chunks_exact_mut(4))
+        {
+            let matches = actual
+                .iter()
+                .zip(expected)
+                .all(|(a, e)| a.abs_diff(*e) <= self.tolerance);
+
+            if matches {
+                // Faded copy of the reference, so mismatches stand out while keeping context.
+                let luma = (expected[0] as u16 + expected[1] as u16 + expected[2] as u16) / 3;
+                let faded = (luma / 4) as u8;
+                diff.copy_from_slice(&[faded, faded, faded, expected[3] / 4]);
+            } else {
+                mismatched_pixels += 1;
+                diff.copy_from_slice(&[255, 0, 0, 255]);
+            }
+        }
+
+        if mismatched_pixels == 0 {
+            return Ok(SnapshotOutcome::Matched);
+        }
+
+        self.write_sibling("actual", &actual)?;
+        let diff_path = self.write_sibling("diff", &diff)?;
+
+        Err(SnapshotError::Mismatch {
+            mismatched_pixels,
+            diff_path,
+        })
+    }
+
+    fn write_sibling(&self, suffix: &str, pixmap: &Pixmap) -> Result<PathBuf, SnapshotError> {
+        let path = self.reference.with_extension(format!("{suffix}.png"));
+        pixmap.save_png(&path).map_err(image_error)?;
+
+        Ok(path)
+    }
+}
+
+/// Renders `drawable` at `bounds` and panics if it does not exactly match the PNG at `reference`.
+pub fn assert_snapshot(drawable: &dyn Drawable, bounds: Bounds, reference: impl AsRef<Path>) {
+    let reference = reference.as_ref();
+
+    if let Err(err) = Snapshot::new(reference).check(drawable, bounds) {
+        panic!("snapshot `{}` failed: {err}", reference.display());
+    }
+}
+
+fn image_error(err: impl std::fmt::Display) -> SnapshotError {
+    SnapshotError::Image(err.to_string())
+}
+
+/// Returns the path of the reference image called `name` among this crate's own snapshots.
+#[cfg(test)]
+pub(crate) fn reference_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(name)
+        .with_extension("png")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        block::{Block, Props},
+        color::Color,
+    };
+
+    use super::*;
+
+    fn red_square(size: usize) -> Block {
+        Block::new(
+            (),
+            Props {
+                background_color: Some(Color::RED.into()),
+                width: Some(size),
+                height: Some(size),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn mismatch_writes_diff_image() {
+        let dir = std::env::temp_dir().join(format!("wunderbar-snapshot-{}", std::process::id()));
+        let reference = dir.join("square.png");
+        let bounds = Bounds::new(0, 0, 4, 4);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let snapshot = Snapshot::new(&reference).update(false);
+        assert!(matches!(
+            snapshot.check(&red_square(4), bounds),
+            Err(SnapshotError::MissingReference(_))
+        ));
+        assert!(!reference.exists());
+
+        assert_eq!(
+            snapshot
+                .clone()
+                .update(true)
+                .check(&red_square(4), bounds)
+                .unwrap(),
+            SnapshotOutcome::Recorded
+        );
+        assert_eq!(
+            snapshot.check(&red_square(4), bounds).unwrap(),
+            SnapshotOutcome::Matched
+        );
+
+        match snapshot.check(&red_square(2), bounds) {
+            Err(SnapshotError::Mismatch {
+                mismatched_pixels,
+                diff_path,
+            }) => {
+                assert_eq!(mismatched_pixels, 12);
+                assert!(diff_path.exists());
+            }
+            other => panic!("expected a mismatch, got {other:?}"),
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
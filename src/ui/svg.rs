@@ -0,0 +1,1387 @@
+//! Vector icons parsed from a subset of SVG.
+//!
+//! Supported are `<path>`, `<rect>`, `<circle>`, `<ellipse>`, `<line>`, `<polyline>` and
+//! `<polygon>` shapes nested in any number of `<g>` groups, along with the `fill`, `stroke`,
+//! `stroke-width`, `stroke-dasharray`, `stroke-dashoffset`, `stroke-linecap`, `stroke-linejoin`,
+//! `stroke-miterlimit`, `fill-rule`, `opacity`, `fill-opacity`, `stroke-opacity` and `transform`
+//! properties, given either as attributes or in a `style` attribute. Anything else, such as
+//! gradients, text or `<use>`, is ignored.
+
+use std::{f64::consts::PI, path::Path as FsPath};
+
+use super::{
+    canvas::{Canvas, Dash, FillRule, LineCap, LineJoin, Path, StrokeStyle},
+    color::Color,
+    geometry::{Bounds, PointF, RectF, Size},
+    Drawable, Error, UiResult,
+};
+
+/// An SVG image, scaled to fit its bounds while keeping its aspect ratio.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svg {
+    view_box: RectF,
+    size: (f64, f64),
+    shapes: Vec<Shape>,
+    props: Props,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Props {
+    /// Color used wherever the SVG refers to `currentColor`.
+    pub color: Color,
+    /// Replaces the color of every filled shape, keeping its opacity.
+    pub fill: Option<Color>,
+    /// Replaces the color of every stroked shape, keeping its opacity.
+    pub stroke: Option<Color>,
+}
+
+impl Default for Props {
+    fn default() -> Self {
+        Self {
+            color: Color::BLACK,
+            fill: None,
+            stroke: None,
+        }
+    }
+}
+
+/// A single shape of an [`Svg`], with its geometry in the coordinate system of the view box.
+#[derive(Debug, Clone, PartialEq)]
+struct Shape {
+    path: Path,
+    fill: Option<Color>,
+    stroke: Option<(Color, StrokeStyle)>,
+}
+
+impl Svg {
+    /// Parses an SVG document.
+    pub fn parse(source: &str, props: Props) -> UiResult<Self> {
+        let document = roxmltree::Document::parse(source).map_err(invalid)?;
+        let root = document.root_element();
+        if root.tag_name().name() != "svg" {
+            return Err(invalid("root element is not <svg>"));
+        }
+
+        let width = root.attribute("width").and_then(parse_length);
+        let height = root.attribute("height").and_then(parse_length);
+        let view_box = match root.attribute("viewBox") {
+            Some(view_box) => {
+                let numbers = parse_numbers(view_box).map_err(invalid)?;
+                let [x, y, width, height] = numbers[..] else {
+                    return Err(invalid("viewBox must have four numbers"));
+                };
+                RectF::new(x, y, width, height)
+            }
+            None => RectF::new(
+                0.0,
+                0.0,
+                width.ok_or_else(|| invalid("missing width and viewBox"))?,
+                height.ok_or_else(|| invalid("missing height and viewBox"))?,
+            ),
+        };
+        if view_box.width <= 0.0 || view_box.height <= 0.0 {
+            return Err(invalid("viewBox must have a positive size"));
+        }
+
+        let mut shapes = Vec::new();
+        collect_shapes(
+            root,
+            &Style::default(),
+            Transform::IDENTITY,
+            &props,
+            &mut shapes,
+        )?;
+
+        Ok(Self {
+            view_box,
+            size: (
+                width.unwrap_or(view_box.width),
+                height.unwrap_or(view_box.height),
+            ),
+            shapes,
+            props,
+        })
+    }
+
+    /// Reads and parses the SVG document at `path`.
+    pub fn load(path: impl AsRef<FsPath>, props: Props) -> UiResult<Self> {
+        let source = std::fs::read_to_string(path).map_err(invalid)?;
+
+        Self::parse(&source, props)
+    }
+
+    /// Size of the image in points, as given by the `width` and `height` of the document, or its
+    /// view box if they are missing.
+    pub fn intrinsic_size(&self) -> (f64, f64) {
+        self.size
+    }
+
+    /// Returns the scale and offset mapping the view box into `bounds`.
+    fn placement(&self, bounds: RectF) -> (f64, PointF) {
+        let scale = (bounds.width / self.view_box.width).min(bounds.height / self.view_box.height);
+        let center = bounds.center();
+        let offset = PointF::new(
+            center.x - (self.view_box.x + self.view_box.width / 2.0) * scale,
+            center.y - (self.view_box.y + self.view_box.height / 2.0) * scale,
+        );
+
+        (scale, offset)
+    }
+}
+
+impl Drawable for Svg {
+    fn content_size(&self, _bounds: Bounds) -> Size {
+        let (width, height) = self.size;
+
+        Size::new(width.ceil() as usize, height.ceil() as usize)
+    }
+
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> UiResult<()> {
+        let (scale, offset) = self.placement(bounds.into());
+
+        canvas.save();
+        for shape in &self.shapes {
+            let path = shape
+                .path
+                .map_points(|p| PointF::new(offset.x + p.x * scale, offset.y + p.y * scale));
+
+            if let Some(fill) = shape.fill {
+                canvas.set_fill_color(fill);
+                canvas.fill_path(&path);
+            }
+            if let Some((stroke, style)) = &shape.stroke {
+                canvas.set_stroke_color(*stroke);
+                canvas.set_stroke_style(&StrokeStyle {
+                    width: style.width * scale,
+                    dash: style.dash.as_ref().map(|dash| {
+                        Dash::new(
+                            dash.lengths.iter().map(|length| length * scale).collect(),
+                            dash.offset * scale,
+                        )
+                    }),
+                    ..style.clone()
+                });
+                canvas.stroke_path(&path);
+            }
+        }
+        canvas.restore();
+
+        Ok(())
+    }
+}
+
+fn invalid(err: impl ToString) -> Error {
+    Error::InvalidSvg(err.to_string())
+}
+
+/// Color of a fill or stroke before `currentColor` is resolved.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SvgPaint {
+    None,
+    Color(Color),
+    CurrentColor,
+}
+
+/// Presentation properties, as inherited from the enclosing elements.
+#[derive(Debug, Clone)]
+struct Style {
+    fill: SvgPaint,
+    stroke: SvgPaint,
+    stroke_width: f64,
+    dash: Option<Vec<f64>>,
+    dash_offset: f64,
+    cap: LineCap,
+    join: LineJoin,
+    miter_limit: f64,
+    fill_rule: FillRule,
+    fill_opacity: f64,
+    stroke_opacity: f64,
+    /// Product of the `opacity` of every enclosing element. Group opacity is approximated by
+    /// applying it to each shape individually.
+    opacity: f64,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            fill: SvgPaint::Color(Color::BLACK),
+            stroke: SvgPaint::None,
+            stroke_width: 1.0,
+            dash: None,
+            dash_offset: 0.0,
+            cap: LineCap::Butt,
+            join: LineJoin::Miter,
+            miter_limit: 4.0,
+            fill_rule: FillRule::NonZero,
+            fill_opacity: 1.0,
+            stroke_opacity: 1.0,
+            opacity: 1.0,
+        }
+    }
+}
+
+impl Style {
+    /// Returns the style of `node`, inheriting from `self`.
+    fn cascade(&self, node: roxmltree::Node) -> UiResult<Self> {
+        let mut style = Self {
+            opacity: self.opacity,
+            ..self.clone()
+        };
+
+        let declarations = node
+            .attributes()
+            .map(|attribute| (attribute.name(), attribute.value()))
+            .chain(node.attribute("style").into_iter().flat_map(|style| {
+                style.split(';').filter_map(|declaration| {
+                    let (name, value) = declaration.split_once(':')?;
+                    Some((name.trim(), value.trim()))
+                })
+            }));
+
+        for (name, value) in declarations {
+            match name {
+                "fill" => style.fill = parse_paint(value, self.fill),
+                "stroke" => style.stroke = parse_paint(value, self.stroke),
+                "stroke-width" => style.stroke_width = parse_length(value).unwrap_or(1.0),
+                "stroke-dasharray" => {
+                    style.dash = match value {
+                        "none" => None,
+                        _ => Some(parse_numbers(value).map_err(invalid)?),
+                    }
+                }
+                "stroke-dashoffset" => style.dash_offset = parse_length(value).unwrap_or(0.0),
+                "stroke-linecap" => {
+                    style.cap = match value {
+                        "round" => LineCap::Round,
+                        "square" => LineCap::Square,
+                        _ => LineCap::Butt,
+                    }
+                }
+                "stroke-linejoin" => {
+                    style.join = match value {
+                        "round" => LineJoin::Round,
+                        "bevel" => LineJoin::Bevel,
+                        _ => LineJoin::Miter,
+                    }
+                }
+                "stroke-miterlimit" => {
+                    style.miter_limit = value.trim().parse::<f64>().unwrap_or(4.0).max(1.0)
+                }
+                "fill-rule" => {
+                    style.fill_rule = match value {
+                        "evenodd" => FillRule::EvenOdd,
+                        _ => FillRule::NonZero,
+                    }
+                }
+                "fill-opacity" => style.fill_opacity = parse_opacity(value),
+                "stroke-opacity" => style.stroke_opacity = parse_opacity(value),
+                "opacity" => style.opacity = self.opacity * parse_opacity(value),
+                _ => {}
+            }
+        }
+
+        Ok(style)
+    }
+
+    fn resolve(paint: SvgPaint, opacity: f64, props: &Props, over: Option<Color>) -> Option<Color> {
+        let color = match paint {
+            SvgPaint::None => return None,
+            SvgPaint::Color(color) => color,
+            SvgPaint::CurrentColor => props.color,
+        };
+        let color = over.unwrap_or(color);
+
+        Some(color.with_alpha(color.alpha() * opacity))
+    }
+
+    fn fill(&self, props: &Props) -> Option<Color> {
+        Self::resolve(
+            self.fill,
+            self.fill_opacity * self.opacity,
+            props,
+            props.fill,
+        )
+    }
+
+    fn stroke(&self, props: &Props, scale: f64) -> Option<(Color, StrokeStyle)> {
+        if self.stroke_width <= 0.0 {
+            return None;
+        }
+        let color = Self::resolve(
+            self.stroke,
+            self.stroke_opacity * self.opacity,
+            props,
+            props.stroke,
+        )?;
+        let dash = self
+            .dash
+            .as_ref()
+            .filter(|lengths| lengths.iter().any(|length| *length > 0.0))
+            .map(|lengths| {
+                Dash::new(
+                    lengths.iter().map(|length| length * scale).collect(),
+                    self.dash_offset * scale,
+                )
+            });
+
+        Some((
+            color,
+            StrokeStyle {
+                width: self.stroke_width * scale,
+                dash,
+                cap: self.cap,
+                join: self.join,
+                miter_limit: self.miter_limit,
+            },
+        ))
+    }
+}
+
+fn collect_shapes(
+    node: roxmltree::Node,
+    parent_style: &Style,
+    parent_transform: Transform,
+    props: &Props,
+    shapes: &mut Vec<Shape>,
+) -> UiResult<()> {
+    let style = parent_style.cascade(node)?;
+    let transform = match node.attribute("transform") {
+        Some(transform) => parent_transform.then(parse_transform(transform)?),
+        None => parent_transform,
+    };
+    let number = |name| node.attribute(name).and_then(parse_length).unwrap_or(0.0);
+
+    let path = match node.tag_name().name() {
+        "svg" | "g" => {
+            for child in node.children().filter(|child| child.is_element()) {
+                collect_shapes(child, &style, transform, props, shapes)?;
+            }
+            return Ok(());
+        }
+        "path" => parse_path_data(node.attribute("d").unwrap_or_default())?,
+        "rect" => {
+            let (x, y, width, height) =
+                (number("x"), number("y"), number("width"), number("height"));
+            let (rx, ry) = match (
+                node.attribute("rx").and_then(parse_length),
+                node.attribute("ry").and_then(parse_length),
+            ) {
+                (Some(rx), Some(ry)) => (rx, ry),
+                (Some(r), None) | (None, Some(r)) => (r, r),
+                (None, None) => (0.0, 0.0),
+            };
+            rect_path(
+                RectF::new(x, y, width, height),
+                rx.clamp(0.0, width / 2.0),
+                ry.clamp(0.0, height / 2.0),
+            )
+        }
+        "circle" => {
+            let r = number("r");
+            ellipse_path(PointF::new(number("cx"), number("cy")), r, r)
+        }
+        "ellipse" => ellipse_path(
+            PointF::new(number("cx"), number("cy")),
+            number("rx"),
+            number("ry"),
+        ),
+        "line" => {
+            let mut path = Path::new();
+            path.move_to(number("x1"), number("y1"))
+                .line_to(number("x2"), number("y2"));
+            path
+        }
+        name @ ("polyline" | "polygon") => {
+            let numbers =
+                parse_numbers(node.attribute("points").unwrap_or_default()).map_err(invalid)?;
+            let mut path = Path::new();
+            for (i, point) in numbers.chunks_exact(2).enumerate() {
+                if i == 0 {
+                    path.move_to(point[0], point[1]);
+                } else {
+                    path.line_to(point[0], point[1]);
+                }
+            }
+            if name == "polygon" && !path.is_empty() {
+                path.close();
+            }
+            path
+        }
+        // Definitions, metadata and unsupported elements are not rendered.
+        _ => return Ok(()),
+    };
+
+    if path.is_empty() {
+        return Ok(());
+    }
+
+    let mut path = path.map_points(|p| transform.apply(p));
+    path.set_fill_rule(style.fill_rule);
+    shapes.push(Shape {
+        path,
+        fill: style.fill(props),
+        stroke: style.stroke(props, transform.scale()),
+    });
+
+    Ok(())
+}
+
+fn rect_path(rect: RectF, rx: f64, ry: f64) -> Path {
+    if rx <= 0.0 || ry <= 0.0 {
+        return Path::rect(rect);
+    }
+
+    let (left, top, right, bottom) = (rect.min_x(), rect.min_y(), rect.max_x(), rect.max_y());
+    let mut path = Path::new();
+    path.move_to(left + rx, top)
+        .line_to(right - rx, top)
+        .corner_to(right, top, right, top + ry)
+        .line_to(right, bottom - ry)
+        .corner_to(right, bottom, right - rx, bottom)
+        .line_to(left + rx, bottom)
+        .corner_to(left, bottom, left, bottom - ry)
+        .line_to(left, top + ry)
+        .corner_to(left, top, left + rx, top)
+        .close();
+
+    path
+}
+
+fn ellipse_path(center: PointF, rx: f64, ry: f64) -> Path {
+    if rx <= 0.0 || ry <= 0.0 {
+        return Path::new();
+    }
+
+    Path::ellipse(RectF::new(center.x - rx, center.y - ry, rx * 2.0, ry * 2.0))
+}
+
+/// An affine transform `[a, b, c, d, e, f]`, mapping `(x, y)` to
+/// `(a * x + c * y + e, b * x + d * y + f)` as in SVG.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Transform([f64; 6]);
+
+impl Transform {
+    const IDENTITY: Self = Self([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+
+    /// Returns the transform applying `other` first, then `self`.
+    fn then(self, other: Self) -> Self {
+        let [a1, b1, c1, d1, e1, f1] = self.0;
+        let [a2, b2, c2, d2, e2, f2] = other.0;
+
+        Self([
+            a1 * a2 + c1 * b2,
+            b1 * a2 + d1 * b2,
+            a1 * c2 + c1 * d2,
+            b1 * c2 + d1 * d2,
+            a1 * e2 + c1 * f2 + e1,
+            b1 * e2 + d1 * f2 + f1,
+        ])
+    }
+
+    fn apply(&self, p: PointF) -> PointF {
+        let [a, b, c, d, e, f] = self.0;
+
+        PointF::new(a * p.x + c * p.y + e, b * p.x + d * p.y + f)
+    }
+
+    /// Average factor by which lengths are scaled, used for stroke widths.
+    fn scale(&self) -> f64 {
+        let [a, b, c, d, ..] = self.0;
+
+        (a * d - b * c).abs().sqrt()
+    }
+}
+
+fn parse_transform(source: &str) -> UiResult<Transform> {
+    let mut transform = Transform::IDENTITY;
+    let mut rest = source.trim();
+
+    while !rest.is_empty() {
+        let (name, after_name) = rest
+            .split_once('(')
+            .ok_or_else(|| invalid(format!("invalid transform `{source}`")))?;
+        let (arguments, after) = after_name
+            .split_once(')')
+            .ok_or_else(|| invalid(format!("invalid transform `{source}`")))?;
+        let arguments = parse_numbers(arguments).map_err(invalid)?;
+
+        let next = match (name.trim(), &arguments[..]) {
+            ("matrix", &[a, b, c, d, e, f]) => Transform([a, b, c, d, e, f]),
+            ("translate", &[x]) => Transform([1.0, 0.0, 0.0, 1.0, x, 0.0]),
+            ("translate", &[x, y]) => Transform([1.0, 0.0, 0.0, 1.0, x, y]),
+            ("scale", &[s]) => Transform([s, 0.0, 0.0, s, 0.0, 0.0]),
+            ("scale", &[x, y]) => Transform([x, 0.0, 0.0, y, 0.0, 0.0]),
+            ("rotate", &[angle]) => rotation(angle),
+            ("rotate", &[angle, cx, cy]) => Transform([1.0, 0.0, 0.0, 1.0, cx, cy])
+                .then(rotation(angle))
+                .then(Transform([1.0, 0.0, 0.0, 1.0, -cx, -cy])),
+            ("skewX", &[angle]) => Transform([1.0, 0.0, angle.to_radians().tan(), 1.0, 0.0, 0.0]),
+            ("skewY", &[angle]) => Transform([1.0, angle.to_radians().tan(), 0.0, 1.0, 0.0, 0.0]),
+            _ => return Err(invalid(format!("invalid transform `{source}`"))),
+        };
+
+        transform = transform.then(next);
+        rest = after.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+    }
+
+    Ok(transform)
+}
+
+fn rotation(angle: f64) -> Transform {
+    let (sin, cos) = angle.to_radians().sin_cos();
+
+    Transform([cos, sin, -sin, cos, 0.0, 0.0])
+}
+
+/// Parses a `fill` or `stroke` value. Values that are not supported, such as references to
+/// gradients without a fallback color, leave the shape unpainted, while values that are not
+/// understood at all fall back to `inherited` as if they were not given.
+fn parse_paint(value: &str, inherited: SvgPaint) -> SvgPaint {
+    match value.trim() {
+        "none" => SvgPaint::None,
+        "currentColor" => SvgPaint::CurrentColor,
+        "inherit" => inherited,
+        value => match value.strip_prefix("url(") {
+            Some(reference) => {
+                let fallback = reference
+                    .split_once(')')
+                    .map_or("", |(_, rest)| rest.trim());
+                match fallback {
+                    "" => SvgPaint::None,
+                    fallback => parse_paint(fallback, SvgPaint::None),
+                }
+            }
+            None => parse_color(value).map_or(inherited, SvgPaint::Color),
+        },
+    }
+}
+
+fn parse_color(value: &str) -> Option<Color> {
+    let channel = |value: u32| value as f64 / 255.0;
+    let value = value.trim();
+
+    if let Some(hex) = value.strip_prefix('#') {
+        let digits = u32::from_str_radix(hex, 16).ok()?;
+        let short = |shift: u32| channel(((digits >> shift) & 0xf) * 0x11);
+        let long = |shift: u32| channel((digits >> shift) & 0xff);
+        return match hex.len() {
+            3 => Some(Color::rgb(short(8), short(4), short(0))),
+            4 => Some(Color::rgba(short(12), short(8), short(4), short(0))),
+            6 => Some(Color::rgb(long(16), long(8), long(0))),
+            8 => Some(Color::rgba(long(24), long(16), long(8), long(0))),
+            _ => None,
+        };
+    }
+
+    if let Some((function, arguments)) = value
+        .strip_suffix(')')
+        .and_then(|value| value.split_once('('))
+    {
+        // Channels are numbers from 0 to 255 or percentages, and alpha is a number from 0 to 1
+        // or a percentage, separated by commas or by whitespace and a slash.
+        let arguments: Vec<_> = arguments
+            .split([',', '/', ' ', '\t', '\n'])
+            .filter(|argument| !argument.is_empty())
+            .map(|argument| match argument.strip_suffix('%') {
+                Some(percentage) => percentage.parse::<f64>().map(|value| (value / 100.0, true)),
+                None => argument.parse::<f64>().map(|value| (value, false)),
+            })
+            .collect::<Result<_, _>>()
+            .ok()?;
+        let channel = |(value, percentage): (f64, bool)| {
+            let value = if percentage { value } else { value / 255.0 };
+            value.clamp(0.0, 1.0)
+        };
+        let alpha = |(value, _): (f64, bool)| value.clamp(0.0, 1.0);
+
+        return match (function.trim(), &arguments[..]) {
+            ("rgb" | "rgba", &[r, g, b]) => Some(Color::rgb(channel(r), channel(g), channel(b))),
+            ("rgb" | "rgba", &[r, g, b, a]) => {
+                Some(Color::rgba(channel(r), channel(g), channel(b), alpha(a)))
+            }
+            _ => None,
+        };
+    }
+
+    let name = value.to_ascii_lowercase();
+    if name == "transparent" {
+        return Some(Color::TRANSPARENT);
+    }
+    let index = NAMED_COLORS
+        .binary_search_by_key(&name.as_str(), |(name, _)| name)
+        .ok()?;
+    let (_, rgb) = NAMED_COLORS[index];
+
+    Some(Color::rgb(
+        channel((rgb >> 16) & 0xff),
+        channel((rgb >> 8) & 0xff),
+        channel(rgb & 0xff),
+    ))
+}
+
+/// The CSS named colors, sorted by name. `transparent` is handled separately, as it is the only
+/// one with an alpha channel.
+const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+fn parse_opacity(value: &str) -> f64 {
+    value.trim().parse::<f64>().unwrap_or(1.0).clamp(0.0, 1.0)
+}
+
+/// Parses a length in user units, ignoring a `px` suffix. Other units are not supported.
+fn parse_length(value: &str) -> Option<f64> {
+    let value = value.trim();
+
+    value.strip_suffix("px").unwrap_or(value).parse().ok()
+}
+
+fn parse_numbers(source: &str) -> Result<Vec<f64>, String> {
+    let mut cursor = Cursor::new(source);
+    let mut numbers = Vec::new();
+
+    while !cursor.at_end() {
+        numbers.push(cursor.number()?);
+    }
+
+    Ok(numbers)
+}
+
+/// Parses SVG path data, as found in the `d` attribute of a `<path>`.
+fn parse_path_data(source: &str) -> UiResult<Path> {
+    let mut cursor = Cursor::new(source);
+    let mut path = Path::new();
+    let mut command = None;
+    let mut current = PointF::default();
+    let mut subpath_start = PointF::default();
+    // Reflected control point of the previous curve, for the shorthand `S` and `T` commands.
+    let mut last_cubic_control: Option<PointF> = None;
+    let mut last_quad_control: Option<PointF> = None;
+
+    while !cursor.at_end() {
+        if let Some(letter) = cursor.command() {
+            if path.is_empty() && !letter.eq_ignore_ascii_case(&'M') {
+                return Err(invalid("path data must start with a move-to command"));
+            }
+            command = Some(letter);
+        } else if command.is_none() {
+            return Err(invalid("path data must start with a move-to command"));
+        }
+        let letter = command.unwrap_or('M');
+        let relative = letter.is_ascii_lowercase();
+        let origin = if relative { current } else { PointF::default() };
+        let point = |cursor: &mut Cursor| -> UiResult<PointF> {
+            let x = cursor.number().map_err(invalid)?;
+            let y = cursor.number().map_err(invalid)?;
+            Ok(PointF::new(origin.x + x, origin.y + y))
+        };
+
+        let (mut cubic_control, mut quad_control) = (None, None);
+        match letter.to_ascii_uppercase() {
+            'M' => {
+                current = point(&mut cursor)?;
+                subpath_start = current;
+                path.move_to(current.x, current.y);
+                // Further coordinate pairs are implicit line-to commands.
+                command = Some(if relative { 'l' } else { 'L' });
+            }
+            'L' => {
+                current = point(&mut cursor)?;
+                path.line_to(current.x, current.y);
+            }
+            'H' => {
+                let x = cursor.number().map_err(invalid)?;
+                current.x = if relative { current.x + x } else { x };
+                path.line_to(current.x, current.y);
+            }
+            'V' => {
+                let y = cursor.number().map_err(invalid)?;
+                current.y = if relative { current.y + y } else { y };
+                path.line_to(current.x, current.y);
+            }
+            'C' => {
+                let c1 = point(&mut cursor)?;
+                let c2 = point(&mut cursor)?;
+                current = point(&mut cursor)?;
+                path.cubic_to(c1.x, c1.y, c2.x, c2.y, current.x, current.y);
+                cubic_control = Some(c2);
+            }
+            'S' => {
+                let c1 = reflect(last_cubic_control, current);
+                let c2 = point(&mut cursor)?;
+                current = point(&mut cursor)?;
+                path.cubic_to(c1.x, c1.y, c2.x, c2.y, current.x, current.y);
+                cubic_control = Some(c2);
+            }
+            'Q' => {
+                let c = point(&mut cursor)?;
+                current = point(&mut cursor)?;
+                path.quad_to(c.x, c.y, current.x, current.y);
+                quad_control = Some(c);
+            }
+            'T' => {
+                let c = reflect(last_quad_control, current);
+                current = point(&mut cursor)?;
+                path.quad_to(c.x, c.y, current.x, current.y);
+                quad_control = Some(c);
+            }
+            'A' => {
+                let rx = cursor.number().map_err(invalid)?;
+                let ry = cursor.number().map_err(invalid)?;
+                let rotation = cursor.number().map_err(invalid)?;
+                let large_arc = cursor.flag().map_err(invalid)?;
+                let sweep = cursor.flag().map_err(invalid)?;
+                let end = point(&mut cursor)?;
+                arc_to(&mut path, current, rx, ry, rotation, large_arc, sweep, end);
+                current = end;
+            }
+            'Z' => {
+                path.close();
+                current = subpath_start;
+            }
+            _ => return Err(invalid(format!("unsupported path command `{letter}`"))),
+        }
+        last_cubic_control = cubic_control;
+        last_quad_control = quad_control;
+
+        if letter.eq_ignore_ascii_case(&'Z') {
+            command = None;
+        }
+    }
+
+    Ok(path)
+}
+
+fn reflect(control: Option<PointF>, around: PointF) -> PointF {
+    match control {
+        Some(control) => PointF::new(2.0 * around.x - control.x, 2.0 * around.y - control.y),
+        None => around,
+    }
+}
+
+/// Adds an elliptical arc from `start` to `end` as a series of cubic Bézier curves, following the
+/// endpoint parameterization of SVG arcs.
+///
+/// See <https://www.w3.org/TR/SVG11/implnote.html#ArcImplementationNotes>.
+#[allow(clippy::too_many_arguments)]
+fn arc_to(
+    path: &mut Path,
+    start: PointF,
+    rx: f64,
+    ry: f64,
+    rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+    end: PointF,
+) {
+    if start == end {
+        return;
+    }
+    let (mut rx, mut ry) = (rx.abs(), ry.abs());
+    if rx == 0.0 || ry == 0.0 {
+        path.line_to(end.x, end.y);
+        return;
+    }
+
+    let (sin, cos) = rotation.to_radians().sin_cos();
+    let dx = (start.x - end.x) / 2.0;
+    let dy = (start.y - end.y) / 2.0;
+    let x1 = cos * dx + sin * dy;
+    let y1 = -sin * dx + cos * dy;
+
+    // Scale the radii up if they are too small to reach from one end to the other.
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let mut coefficient = (numerator / denominator).max(0.0).sqrt();
+    if large_arc == sweep {
+        coefficient = -coefficient;
+    }
+    let cx1 = coefficient * rx * y1 / ry;
+    let cy1 = -coefficient * ry * x1 / rx;
+    let cx = cos * cx1 - sin * cy1 + (start.x + end.x) / 2.0;
+    let cy = sin * cx1 + cos * cy1 + (start.y + end.y) / 2.0;
+
+    let angle = |ux: f64, uy: f64, vx: f64, vy: f64| {
+        let sign = if ux * vy - uy * vx < 0.0 { -1.0 } else { 1.0 };
+        let dot = (ux * vx + uy * vy) / (ux.hypot(uy) * vx.hypot(vy));
+        sign * dot.clamp(-1.0, 1.0).acos()
+    };
+    let theta = angle(1.0, 0.0, (x1 - cx1) / rx, (y1 - cy1) / ry);
+    let mut delta = angle(
+        (x1 - cx1) / rx,
+        (y1 - cy1) / ry,
+        (-x1 - cx1) / rx,
+        (-y1 - cy1) / ry,
+    );
+    if !sweep && delta > 0.0 {
+        delta -= 2.0 * PI;
+    } else if sweep && delta < 0.0 {
+        delta += 2.0 * PI;
+    }
+
+    // Split into segments of at most a quarter turn, each approximated by one cubic curve.
+    let segments = (delta.abs() / (PI / 2.0)).ceil().max(1.0) as usize;
+    let step = delta / segments as f64;
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+    let point_at = |t: f64| {
+        let (sin_t, cos_t) = t.sin_cos();
+        let (x, y) = (rx * cos_t, ry * sin_t);
+        PointF::new(cx + cos * x - sin * y, cy + sin * x + cos * y)
+    };
+    let derivative_at = |t: f64| {
+        let (sin_t, cos_t) = t.sin_cos();
+        let (x, y) = (-rx * sin_t, ry * cos_t);
+        PointF::new(cos * x - sin * y, sin * x + cos * y)
+    };
+
+    for i in 0..segments {
+        let t1 = theta + step * i as f64;
+        let t2 = t1 + step;
+        let (p1, d1) = (point_at(t1), derivative_at(t1));
+        let (p2, d2) = (point_at(t2), derivative_at(t2));
+        // Land exactly on the requested end point, regardless of rounding.
+        let p2 = if i + 1 == segments { end } else { p2 };
+
+        path.cubic_to(
+            p1.x + k * d1.x,
+            p1.y + k * d1.y,
+            p2.x - k * d2.x,
+            p2.y - k * d2.y,
+            p2.x,
+            p2.y,
+        );
+    }
+}
+
+/// Tokenizer for the numbers, flags and commands of SVG attribute values.
+struct Cursor<'a> {
+    source: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source: source.as_bytes(),
+            position: 0,
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        while let Some(byte) = self.peek() {
+            if byte.is_ascii_whitespace() || byte == b',' {
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.source.get(self.position).copied()
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_separators();
+        self.position >= self.source.len()
+    }
+
+    /// Consumes a path command letter, if one comes next.
+    fn command(&mut self) -> Option<char> {
+        self.skip_separators();
+        let byte = self.peek().filter(|byte| byte.is_ascii_alphabetic())?;
+        // `e` and `E` only appear inside numbers, never as commands.
+        if byte.eq_ignore_ascii_case(&b'e') {
+            return None;
+        }
+        self.position += 1;
+
+        Some(byte as char)
+    }
+
+    fn flag(&mut self) -> Result<bool, String> {
+        self.skip_separators();
+        let flag = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(format!("expected a flag at offset {}", self.position)),
+        };
+        self.position += 1;
+
+        Ok(flag)
+    }
+
+    fn number(&mut self) -> Result<f64, String> {
+        self.skip_separators();
+        let start = self.position;
+        let digits = |cursor: &mut Self| {
+            let before = cursor.position;
+            while cursor.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+                cursor.position += 1;
+            }
+            cursor.position > before
+        };
+
+        if matches!(self.peek(), Some(b'+' | b'-')) {
+            self.position += 1;
+        }
+        let mut has_digits = digits(self);
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            has_digits |= digits(self);
+        }
+        if !has_digits {
+            return Err(format!("expected a number at offset {start}"));
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            let mantissa_end = self.position;
+            self.position += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.position += 1;
+            }
+            if !digits(self) {
+                self.position = mantissa_end;
+            }
+        }
+
+        std::str::from_utf8(&self.source[start..self.position])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .ok_or_else(|| format!("invalid number at offset {start}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        block::{self, Block},
+        canvas::{
+            recording::{DisplayItem, RecordingCanvas},
+            PathElement,
+        },
+        layout::{self, Direction, Layout},
+        snapshot::{assert_snapshot, reference_path},
+    };
+
+    use super::*;
+
+    const ICON: &str = r##"
+        <svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" viewBox="0 0 24 24">
+            <title>Test icon</title>
+            <rect x="2" y="2" width="20" height="20" rx="5" fill="#2a2a33"/>
+            <g transform="translate(12 12)" fill="currentColor">
+                <circle r="3"/>
+                <path d="M-7 2 a8 8 0 0 1 14 0" fill="none" stroke="currentColor"
+                      stroke-width="2" style="stroke-opacity: 0.6; stroke-linecap: round"/>
+                <polygon points="-1,5 1,5 0,8" fill="#f80"/>
+            </g>
+            <line x1="4" y1="20" x2="20" y2="20" stroke="#ffffff" stroke-dasharray="2 1"/>
+        </svg>
+    "##;
+
+    fn points(path: &Path) -> Vec<PointF> {
+        path.elements()
+            .iter()
+            .filter_map(|element| match *element {
+                PathElement::MoveTo(p)
+                | PathElement::LineTo(p)
+                | PathElement::QuadTo(_, p)
+                | PathElement::CubicTo(_, _, p) => Some(p),
+                PathElement::Close => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parses_path_data() {
+        let path = parse_path_data("M1 2 3,4l1-1H0v.5.5zm10 0 1e1 0").unwrap();
+
+        assert_eq!(
+            points(&path),
+            [
+                PointF::new(1.0, 2.0),
+                PointF::new(3.0, 4.0),
+                PointF::new(4.0, 3.0),
+                PointF::new(0.0, 3.0),
+                PointF::new(0.0, 3.5),
+                PointF::new(0.0, 4.0),
+                PointF::new(11.0, 2.0),
+                PointF::new(21.0, 2.0),
+            ]
+        );
+        assert!(parse_path_data("L1 2").is_err());
+        assert!(parse_path_data("M1").is_err());
+    }
+
+    #[test]
+    fn arcs_end_at_target() {
+        let path = parse_path_data("M0 0 A5 5 0 1 1 10 0 a1 1 0 00 -20 0").unwrap();
+        let ends = points(&path);
+
+        // A half circle takes two quarter-turn segments. The radius of the second arc is too small
+        // to reach its end, so it is scaled up to a half circle as well.
+        assert_eq!(ends.len(), 5);
+        assert_eq!(ends[2], PointF::new(10.0, 0.0));
+        assert_eq!(ends[4], PointF::new(-10.0, 0.0));
+        // Both arcs bulge upwards: the first sweeps clockwise, the second counterclockwise.
+        let bounds = path.bounds().unwrap();
+        assert!((bounds.min_y() + 10.0).abs() < 1e-9, "{bounds:?}");
+        assert!(bounds.max_y().abs() < 1e-9, "{bounds:?}");
+    }
+
+    #[test]
+    fn parses_colors_and_transforms() {
+        assert_eq!(parse_color("#f00"), Some(Color::RED));
+        assert_eq!(parse_color("#0000ff"), Some(Color::BLUE));
+        assert_eq!(parse_color("rgb(0, 255, 0)"), Some(Color::GREEN));
+        assert_eq!(parse_color("Lime"), Some(Color::GREEN));
+        assert_eq!(
+            parse_color("chartreuse"),
+            Some(Color::rgb(127.0 / 255.0, 1.0, 0.0))
+        );
+        assert_eq!(
+            parse_color("#ff000080"),
+            Some(Color::rgba(1.0, 0.0, 0.0, 128.0 / 255.0))
+        );
+        assert_eq!(
+            parse_color("#00f8"),
+            Some(Color::rgba(0.0, 0.0, 1.0, 136.0 / 255.0))
+        );
+        assert_eq!(
+            parse_color("rgba(255, 0, 0, 0.5)"),
+            Some(Color::rgba(1.0, 0.0, 0.0, 0.5))
+        );
+        assert_eq!(
+            parse_color("rgb(0% 100% 0% / 25%)"),
+            Some(Color::rgba(0.0, 1.0, 0.0, 0.25))
+        );
+        assert_eq!(parse_color("wobble"), None);
+        assert_eq!(parse_color("rgb(1, 2)"), None);
+
+        let inherited = SvgPaint::Color(Color::BLUE);
+        assert_eq!(parse_paint("inherit", inherited), inherited);
+        assert_eq!(parse_paint("wobble", inherited), inherited);
+        assert_eq!(parse_paint("url(#glow)", inherited), SvgPaint::None);
+        assert_eq!(
+            parse_paint("url(#glow) red", inherited),
+            SvgPaint::Color(Color::RED)
+        );
+
+        let transform = parse_transform("translate(10,0) scale(2) rotate(90)").unwrap();
+        let p = transform.apply(PointF::new(1.0, 0.0));
+        assert!(
+            (p.x - 10.0).abs() < 1e-9 && (p.y - 2.0).abs() < 1e-9,
+            "{p:?}"
+        );
+        assert!(parse_transform("wobble(1)").is_err());
+    }
+
+    #[test]
+    fn recolors_and_scales_into_bounds() {
+        let svg = Svg::parse(
+            ICON,
+            Props {
+                color: Color::WHITE,
+                stroke: Some(Color::RED),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            svg.content_size(Bounds::new(0, 0, 100, 100)),
+            Size::new(24, 24)
+        );
+
+        let list = RecordingCanvas::record(&svg, Bounds::new(0, 0, 48, 96)).unwrap();
+        let fills: Vec<_> = list
+            .items()
+            .iter()
+            .filter_map(|item| match item {
+                DisplayItem::SetFillColor(color) => Some(*color),
+                _ => None,
+            })
+            .collect();
+        let strokes: Vec<_> = list
+            .items()
+            .iter()
+            .filter_map(|item| match item {
+                DisplayItem::SetStrokeColor(color) => Some(*color),
+                _ => None,
+            })
+            .collect();
+        let stroke_styles: Vec<_> = list
+            .items()
+            .iter()
+            .filter_map(|item| match item {
+                DisplayItem::SetStrokeStyle(style) => Some((style.width, style.cap)),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(fills.len(), 4);
+        assert_eq!(fills[1], Color::WHITE);
+        assert_eq!(strokes, [Color::RED.with_alpha(0.6), Color::RED]);
+        assert_eq!(stroke_styles, [(4.0, LineCap::Round), (2.0, LineCap::Butt)]);
+
+        // The 24x24 view box is scaled by 2 and centered vertically.
+        let Some(DisplayItem::FillPath(background)) = list
+            .items()
+            .iter()
+            .find(|item| matches!(item, DisplayItem::FillPath(_)))
+        else {
+            panic!("background was not filled");
+        };
+        assert_eq!(background.bounds(), Some(RectF::new(4.0, 28.0, 40.0, 40.0)));
+    }
+
+    #[test]
+    fn rejects_invalid_documents() {
+        assert!(matches!(
+            Svg::parse("<html/>", Props::default()),
+            Err(Error::InvalidSvg(_))
+        ));
+        assert!(matches!(
+            Svg::parse("<svg><path d=\"M0 0 X\"/></svg>", Props::default()),
+            Err(Error::InvalidSvg(_))
+        ));
+    }
+
+    #[test]
+    fn skips_gradient_fills() {
+        let svg = Svg::parse(
+            r##"<svg viewBox="0 0 8 8">
+                <defs>
+                    <linearGradient id="sky">
+                        <stop offset="0" stop-color="navy"/>
+                        <stop offset="1" stop-color="skyblue"/>
+                    </linearGradient>
+                </defs>
+                <rect width="8" height="4" fill="url(#sky)"/>
+                <rect y="4" width="8" height="4" fill="url(#sky) rgba(255, 0, 0, 0.5)"/>
+                <circle cx="4" cy="4" r="2" fill="tomato" stroke="url(#sky)"/>
+            </svg>"##,
+            Props::default(),
+        )
+        .unwrap();
+
+        let list = RecordingCanvas::record(&svg, Bounds::new(0, 0, 8, 8)).unwrap();
+        let fills: Vec<_> = list
+            .items()
+            .iter()
+            .filter_map(|item| match item {
+                DisplayItem::SetFillColor(color) => Some(*color),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            fills,
+            [
+                Color::rgba(1.0, 0.0, 0.0, 0.5),
+                Color::rgb(1.0, 99.0 / 255.0, 71.0 / 255.0),
+            ]
+        );
+        assert!(!list
+            .items()
+            .iter()
+            .any(|item| matches!(item, DisplayItem::StrokePath(_))));
+    }
+
+    #[test]
+    fn svg_icons() {
+        let icon = |size, props| {
+            Block::new(
+                Svg::parse(ICON, props).unwrap(),
+                block::Props {
+                    width: Some(size),
+                    height: Some(size),
+                    ..Default::default()
+                },
+            )
+        };
+        let row = Layout::with_children(
+            vec![
+                icon(
+                    16,
+                    Props {
+                        color: Color::WHITE,
+                        ..Default::default()
+                    },
+                ),
+                icon(
+                    32,
+                    Props {
+                        color: Color::WHITE,
+                        ..Default::default()
+                    },
+                ),
+                icon(
+                    32,
+                    Props {
+                        color: Color::rgb(0.4, 1.0, 0.5),
+                        fill: Some(Color::rgb(0.2, 0.4, 0.8)),
+                        stroke: Some(Color::rgb(1.0, 0.8, 0.0)),
+                    },
+                ),
+            ],
+            layout::Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(&row, Bounds::new(0, 0, 80, 32), reference_path("svg_icons"));
+    }
+}
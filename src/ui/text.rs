@@ -0,0 +1,637 @@
+use std::time::Duration;
+
+use super::{
+    canvas::Canvas,
+    color::Color,
+    font::Font,
+    geometry::{Bounds, PointF, Size},
+    paint::Paint,
+    Drawable, UiResult,
+};
+
+const ELLIPSIS: &str = "\u{2026}";
+
+/// A single line of text.
+pub struct Text {
+    content: String,
+    props: Props,
+    elapsed: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Props {
+    pub font: Font,
+    /// Paint the glyphs are filled with. Gradients span the measured line box of the text.
+    pub fill: Paint,
+    /// Extra space in points between consecutive characters. Can be negative to tighten text.
+    pub letter_spacing: f64,
+    /// What to do when the text is wider than its bounds. Ignored for the spans of a
+    /// [`RichText`].
+    pub overflow: Overflow,
+}
+
+impl Default for Props {
+    fn default() -> Self {
+        Self {
+            font: Font::default(),
+            fill: Color::BLACK.into(),
+            letter_spacing: 0.0,
+            overflow: Overflow::default(),
+        }
+    }
+}
+
+/// How a [`Text`] wider than its bounds is shown. In every case, the text reports a width no
+/// larger than its bounds and never draws outside of them.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Overflow {
+    /// The text is cut off at the edge of its bounds.
+    #[default]
+    Clip,
+    /// Characters are removed at the given position and replaced with an ellipsis so the text
+    /// fits.
+    Ellipsis(EllipsisPosition),
+    /// The text scrolls continuously from right to left, as driven by [`Text::set_elapsed`].
+    Marquee(Marquee),
+}
+
+/// Where characters are removed from text truncated by [`Overflow::Ellipsis`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EllipsisPosition {
+    /// "…the end", keeping the end of the text.
+    Start,
+    /// "the b…g end", keeping both ends of the text.
+    Middle,
+    /// "the begin…", keeping the beginning of the text.
+    #[default]
+    End,
+}
+
+/// Parameters of [`Overflow::Marquee`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Marquee {
+    /// Scrolling speed in points per second.
+    pub speed: f64,
+    /// Space in points between the end of the text and its next repetition.
+    pub gap: f64,
+}
+
+impl Default for Marquee {
+    fn default() -> Self {
+        Self {
+            speed: 30.0,
+            gap: 24.0,
+        }
+    }
+}
+
+impl Text {
+    pub fn new(content: impl Into<String>, props: Props) -> Self {
+        Self {
+            content: content.into(),
+            props,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn set_content(&mut self, content: impl Into<String>) {
+        self.content = content.into();
+    }
+
+    pub fn props(&self) -> &Props {
+        &self.props
+    }
+
+    /// Sets the time since the text started being shown, which determines how far an
+    /// [`Overflow::Marquee`] has scrolled. The owner of the text is responsible for advancing it
+    /// and redrawing.
+    pub fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed;
+    }
+
+    fn measure(&self) -> f64 {
+        self.props
+            .font
+            .measure(&self.content, self.props.letter_spacing)
+    }
+}
+
+impl Drawable for Text {
+    /// The measured advance of the text, limited to the width of `bounds`, by the height of the
+    /// font's line box, each rounded up to whole points.
+    fn content_size(&self, bounds: Bounds) -> Size {
+        let width = (self.measure().ceil() as usize).min(bounds.size.width);
+        let height = self.props.font.metrics().height();
+
+        Size::new(width, height.ceil() as usize)
+    }
+
+    fn baseline(&self, _bounds: Bounds) -> Option<f64> {
+        Some(self.props.font.metrics().ascent)
+    }
+
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> UiResult<()> {
+        let origin = PointF::new(
+            bounds.position.x as f64,
+            bounds.position.y as f64 + self.props.font.metrics().ascent,
+        );
+        let available = bounds.size.width as f64;
+        let width = self.measure();
+
+        if width <= available {
+            fill_run(canvas, &self.content, origin, &self.props);
+            return Ok(());
+        }
+
+        canvas.save();
+        canvas.clip_rect(bounds.into());
+        match self.props.overflow {
+            Overflow::Clip => fill_run(canvas, &self.content, origin, &self.props),
+            Overflow::Ellipsis(position) => {
+                let truncated = ellipsize(&self.content, &self.props, available, position);
+                fill_run(canvas, &truncated, origin, &self.props);
+            }
+            Overflow::Marquee(Marquee { speed, gap }) => {
+                let period = width + gap.max(0.0);
+                let offset = (self.elapsed.as_secs_f64() * speed).rem_euclid(period);
+
+                // A second copy follows the first to fill the space it scrolls away from.
+                for start in [-offset, period - offset] {
+                    let origin = PointF::new(origin.x + start, origin.y);
+                    fill_run(canvas, &self.content, origin, &self.props);
+                }
+            }
+        }
+        canvas.restore();
+
+        Ok(())
+    }
+}
+
+/// Returns the longest truncation of `content` with an ellipsis at `position` that fits within
+/// `max_width`, or just the ellipsis if nothing else fits.
+fn ellipsize(content: &str, props: &Props, max_width: f64, position: EllipsisPosition) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    let candidate = |kept: usize| -> String {
+        let (head, tail) = match position {
+            EllipsisPosition::Start => (0, kept),
+            EllipsisPosition::Middle => (kept - kept / 2, kept / 2),
+            EllipsisPosition::End => (kept, 0),
+        };
+        let head: String = chars[..head].iter().collect();
+        let tail: String = chars[chars.len() - tail..].iter().collect();
+
+        format!("{}{ELLIPSIS}{}", head.trim_end(), tail.trim_start())
+    };
+    let fits = |text: &str| props.font.measure(text, props.letter_spacing) <= max_width;
+
+    // The width only grows with the number of kept characters, so search for the largest count
+    // that still fits.
+    let (mut low, mut high) = (0, chars.len());
+    while low < high {
+        let mid = (low + high).div_ceil(2);
+        if fits(&candidate(mid)) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    candidate(low)
+}
+
+/// A run of text within a [`RichText`], styled independently of the other spans.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    pub content: String,
+    pub props: Props,
+}
+
+impl Span {
+    pub fn new(content: impl Into<String>, props: Props) -> Self {
+        Self {
+            content: content.into(),
+            props,
+        }
+    }
+}
+
+/// A single line of text made up of differently styled [`Span`]s, laid out one after the other
+/// on a shared baseline.
+///
+/// The line is as tall as the tallest ascent plus the deepest descent among its spans, so mixing
+/// font sizes keeps every span's glyphs inside the measured size.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RichText {
+    spans: Vec<Span>,
+}
+
+impl RichText {
+    pub fn new(spans: Vec<Span>) -> Self {
+        Self { spans }
+    }
+
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    pub fn spans_mut(&mut self) -> &mut Vec<Span> {
+        &mut self.spans
+    }
+
+    /// Returns the largest ascent and descent among the spans.
+    fn line_metrics(&self) -> (f64, f64) {
+        self.spans
+            .iter()
+            .fold((0.0, 0.0), |(ascent, descent), span| {
+                let metrics = span.props.font.metrics();
+                (ascent.max(metrics.ascent), descent.max(metrics.descent))
+            })
+    }
+}
+
+impl Drawable for RichText {
+    fn content_size(&self, _bounds: Bounds) -> Size {
+        let width: f64 = self
+            .spans
+            .iter()
+            .map(|span| {
+                span.props
+                    .font
+                    .measure(&span.content, span.props.letter_spacing)
+            })
+            .sum();
+        let (ascent, descent) = self.line_metrics();
+
+        Size::new(width.ceil() as usize, (ascent + descent).ceil() as usize)
+    }
+
+    fn baseline(&self, _bounds: Bounds) -> Option<f64> {
+        (!self.spans.is_empty()).then(|| self.line_metrics().0)
+    }
+
+    fn draw(&self, canvas: &mut dyn Canvas, bounds: Bounds) -> UiResult<()> {
+        let (ascent, _) = self.line_metrics();
+        let mut origin = PointF::new(bounds.position.x as f64, bounds.position.y as f64 + ascent);
+
+        for span in &self.spans {
+            fill_run(canvas, &span.content, origin, &span.props);
+            origin.x += span
+                .props
+                .font
+                .measure(&span.content, span.props.letter_spacing);
+        }
+
+        Ok(())
+    }
+}
+
+/// Fills `content` styled by `props`, with the left end of its baseline at `origin`.
+fn fill_run(canvas: &mut dyn Canvas, content: &str, origin: PointF, props: &Props) {
+    let Props {
+        font,
+        fill,
+        letter_spacing,
+        ..
+    } = props;
+
+    canvas.save();
+    match fill {
+        Paint::Solid(color) => canvas.set_fill_color(*color),
+        paint => canvas.set_fill_paint(paint, font.line_bounds(content, origin, *letter_spacing)),
+    }
+    canvas.fill_text(content, origin, font, *letter_spacing);
+    canvas.restore();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        block::{self, Block},
+        canvas::recording::{DisplayItem, RecordingCanvas},
+        font::{FontCollection, FontDescriptor, FontWeight, DEFAULT_FAMILY},
+        layout::{self, Direction, Layout},
+        snapshot::{assert_snapshot, reference_path},
+    };
+
+    use super::*;
+
+    fn label(content: &str) -> Text {
+        Text::new(
+            content,
+            Props {
+                fill: Color::WHITE.into(),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn content_size_uses_font_metrics() {
+        let text = label("12:45");
+        let font = &text.props().font;
+        let size = text.content_size(Bounds::new(0, 0, 100, 100));
+
+        assert_eq!(size.width, font.measure("12:45", 0.0).ceil() as usize);
+        assert_eq!(size.height, font.metrics().height().ceil() as usize);
+
+        // Block min/max sizing builds on the measured size.
+        let block = Block::new(
+            label("12:45"),
+            block::Props {
+                max_width: Some(20),
+                min_height: Some(30),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            block.content_size(Bounds::new(0, 0, 100, 100)),
+            Size::new(20, 30)
+        );
+        assert_eq!(
+            block.content_size(Bounds::new(0, 0, 10, 100)),
+            Size::new(10, 30)
+        );
+    }
+
+    #[test]
+    fn draws_on_baseline() {
+        let fonts = FontCollection::bundled();
+        let font = fonts
+            .resolve(&FontDescriptor::new(DEFAULT_FAMILY, 16.0).weight(FontWeight::BOLD))
+            .unwrap();
+        let ascent = font.metrics().ascent;
+        let text = Text::new(
+            "Hi",
+            Props {
+                font,
+                letter_spacing: 1.0,
+                ..Default::default()
+            },
+        );
+
+        let list = RecordingCanvas::record(&text, Bounds::new(4, 2, 100, 30)).unwrap();
+
+        assert_eq!(
+            list.items()[2],
+            DisplayItem::FillText {
+                text: "Hi".to_string(),
+                origin: PointF::new(4.0, 2.0 + ascent),
+                font: FontDescriptor::new(DEFAULT_FAMILY, 16.0).weight(FontWeight::BOLD),
+                letter_spacing: 1.0,
+            }
+        );
+    }
+
+    #[test]
+    fn labels_in_a_row() {
+        let fonts = FontCollection::bundled();
+        let bold = fonts
+            .resolve(&FontDescriptor::new(DEFAULT_FAMILY, 13.0).weight(FontWeight::BOLD))
+            .unwrap();
+        let row = Layout::with_children(
+            vec![
+                Block::new(
+                    label("Wed 12:45"),
+                    block::Props {
+                        width: Some(76),
+                        ..Default::default()
+                    },
+                ),
+                Block::new(
+                    Text::new(
+                        "86%",
+                        Props {
+                            font: bold,
+                            fill: Color::rgb(0.4, 1.0, 0.5).into(),
+                            letter_spacing: 0.5,
+                            ..Default::default()
+                        },
+                    ),
+                    block::Props::default(),
+                ),
+            ],
+            layout::Props {
+                direction: Direction::Row,
+                ..Default::default()
+            },
+        );
+        let bar = Block::new(
+            row,
+            block::Props {
+                background_color: Some(Color::rgb(0.1, 0.1, 0.12).into()),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &bar,
+            Bounds::new(0, 0, 120, 20),
+            reference_path("text_labels"),
+        );
+    }
+
+    #[test]
+    fn spans_share_baseline() {
+        let fonts = FontCollection::bundled();
+        let small = Font::default().with_size(10.0);
+        let large = fonts
+            .resolve(&FontDescriptor::new(DEFAULT_FAMILY, 20.0).weight(FontWeight::BOLD))
+            .unwrap();
+        let text = RichText::new(vec![
+            Span::new(
+                "CPU ",
+                Props {
+                    font: small.clone(),
+                    ..Default::default()
+                },
+            ),
+            Span::new(
+                "23%",
+                Props {
+                    font: large.clone(),
+                    ..Default::default()
+                },
+            ),
+        ]);
+
+        let width = small.measure("CPU ", 0.0) + large.measure("23%", 0.0);
+        let height = large.metrics().height();
+        assert_eq!(
+            text.content_size(Bounds::new(0, 0, 200, 40)),
+            Size::new(width.ceil() as usize, height.ceil() as usize)
+        );
+
+        let list = RecordingCanvas::record(&text, Bounds::new(0, 0, 200, 40)).unwrap();
+        let origins: Vec<_> = list
+            .items()
+            .iter()
+            .filter_map(|item| match item {
+                DisplayItem::FillText { origin, .. } => Some(*origin),
+                _ => None,
+            })
+            .collect();
+        let baseline = large.metrics().ascent;
+
+        assert_eq!(
+            origins,
+            [
+                PointF::new(0.0, baseline),
+                PointF::new(small.measure("CPU ", 0.0), baseline)
+            ]
+        );
+    }
+
+    #[test]
+    fn rich_text_label() {
+        let fonts = FontCollection::bundled();
+        let bold = fonts
+            .resolve(&FontDescriptor::new(DEFAULT_FAMILY, 13.0).weight(FontWeight::BOLD))
+            .unwrap();
+        let text = RichText::new(vec![
+            Span::new(
+                "CPU ",
+                Props {
+                    fill: Color::rgba(1.0, 1.0, 1.0, 0.5).into(),
+                    font: Font::default().with_size(11.0),
+                    ..Default::default()
+                },
+            ),
+            Span::new(
+                "23%",
+                Props {
+                    font: bold,
+                    fill: Color::WHITE.into(),
+                    ..Default::default()
+                },
+            ),
+        ]);
+        let bar = Block::new(
+            text,
+            block::Props {
+                background_color: Some(Color::rgb(0.1, 0.1, 0.12).into()),
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(&bar, Bounds::new(0, 0, 64, 20), reference_path("rich_text"));
+    }
+
+    fn overflowing(overflow: Overflow) -> Text {
+        Text::new(
+            "Never Gonna Give You Up",
+            Props {
+                fill: Color::WHITE.into(),
+                overflow,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn drawn_texts(text: &Text, bounds: Bounds) -> Vec<(String, PointF)> {
+        RecordingCanvas::record(text, bounds)
+            .unwrap()
+            .into_iter()
+            .filter_map(|item| match item {
+                DisplayItem::FillText { text, origin, .. } => Some((text, origin)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn overflowing_text_is_clamped_and_clipped() {
+        let text = overflowing(Overflow::Clip);
+        let bounds = Bounds::new(0, 0, 50, 20);
+
+        assert_eq!(text.content_size(bounds).width, 50);
+
+        let list = RecordingCanvas::record(&text, bounds).unwrap();
+        assert_eq!(list.items()[1], DisplayItem::ClipRect(bounds.into()));
+    }
+
+    #[test]
+    fn ellipsis_keeps_requested_end() {
+        let bounds = Bounds::new(0, 0, 80, 20);
+        let truncated = |position| {
+            let text = overflowing(Overflow::Ellipsis(position));
+            let (content, _) = drawn_texts(&text, bounds).remove(0);
+            assert!(text.props().font.measure(&content, 0.0) <= 80.0);
+
+            content
+        };
+
+        assert_eq!(truncated(EllipsisPosition::End), "Never Go\u{2026}");
+        assert_eq!(truncated(EllipsisPosition::Start), "\u{2026}ve You Up");
+        assert_eq!(truncated(EllipsisPosition::Middle), "Neve\u{2026}u Up");
+
+        // Text that fits is left alone.
+        let text = overflowing(Overflow::Ellipsis(EllipsisPosition::End));
+        let (content, _) = drawn_texts(&text, Bounds::new(0, 0, 400, 20)).remove(0);
+        assert_eq!(content, "Never Gonna Give You Up");
+    }
+
+    #[test]
+    fn marquee_scrolls_with_elapsed_time() {
+        let mut text = overflowing(Overflow::Marquee(Marquee {
+            speed: 10.0,
+            gap: 20.0,
+        }));
+        let bounds = Bounds::new(0, 0, 50, 20);
+        let period = text.measure() + 20.0;
+        let xs = |text: &Text| -> Vec<f64> {
+            drawn_texts(text, bounds)
+                .into_iter()
+                .map(|(_, origin)| origin.x)
+                .collect()
+        };
+
+        assert_eq!(xs(&text), [0.0, period]);
+
+        text.set_elapsed(Duration::from_millis(1500));
+        assert_eq!(xs(&text), [-15.0, period - 15.0]);
+
+        // Scrolling wraps around once a full repetition has gone by.
+        text.set_elapsed(Duration::from_secs_f64((period + 5.0) / 10.0));
+        let wrapped = xs(&text);
+        assert!((wrapped[0] + 5.0).abs() < 1e-6, "{wrapped:?}");
+    }
+
+    #[test]
+    fn overflow_policies() {
+        let row = |text| {
+            Block::new(
+                text,
+                block::Props {
+                    background_color: Some(Color::rgb(0.1, 0.1, 0.12).into()),
+                    max_width: Some(90),
+                    ..Default::default()
+                },
+            )
+        };
+        let mut marquee = overflowing(Overflow::Marquee(Marquee::default()));
+        marquee.set_elapsed(Duration::from_secs(2));
+        let column = Layout::with_children(
+            vec![
+                row(overflowing(Overflow::Clip)),
+                row(overflowing(Overflow::Ellipsis(EllipsisPosition::End))),
+                row(overflowing(Overflow::Ellipsis(EllipsisPosition::Middle))),
+                row(marquee),
+            ],
+            layout::Props {
+                direction: Direction::Column,
+                ..Default::default()
+            },
+        );
+
+        assert_snapshot(
+            &column,
+            Bounds::new(0, 0, 120, 64),
+            reference_path("text_overflow"),
+        );
+    }
+}
@@ -1,8 +1,8 @@
-use core_graphics::context::{CGContext, CGContextRef};
+use core_graphics::context::CGContext;
 
 use crate::ffi::sls::SlsWindow;
 
-use super::{app::App, geometry::Bounds, Drawable, UiResult};
+use super::{app::App, canvas::cg::CgCanvas, geometry::Bounds, Drawable, UiResult};
 
 pub use crate::ffi::sls::CgsWindowTags as WindowTags;
 
@@ -79,7 +79,7 @@ impl<'app> Window<'app> {
         let bounds = self.bounds;
         let ctx = self.get_context_ref()?;
 
-        drawable.draw(ctx, bounds)?;
+        drawable.draw(&mut CgCanvas::new(ctx, bounds.size.height as f64), bounds)?;
         ctx.flush();
         self.inner.flush_window_content_region()?;
 